@@ -1,7 +1,9 @@
 use crate::{
     consoles::{
-        apply_all_batches, default_gutter_size, replace_meshes, update_mouse_position,
-        update_timing, window_resize, ScreenScaler,
+        apply_all_batches, apply_scaling_mode, change_console_fonts, default_gutter_size,
+        replace_meshes, update_clear_color, update_cursor_visibility, update_keyboard,
+        update_gamepads, update_mouse_position, update_mouse_wheel, update_timing, window_resize,
+        BracketPerfStats, ScreenScaler,
     },
     fix_images, load_terminals, update_consoles, RandomNumbers, TerminalBuilderFont, TerminalLayer,
 };
@@ -31,6 +33,7 @@ pub struct BTermBuilder {
     pub(crate) scaling_mode: TerminalScalingMode,
     pub(crate) gutter: f32,
     pub(crate) auto_apply_batches: bool,
+    pub(crate) linear_filtering: bool,
 }
 
 impl BTermBuilder {
@@ -46,6 +49,7 @@ impl BTermBuilder {
             scaling_mode: TerminalScalingMode::Stretch,
             gutter: default_gutter_size(),
             auto_apply_batches: true,
+            linear_filtering: false,
         }
     }
 
@@ -71,6 +75,7 @@ impl BTermBuilder {
             scaling_mode: TerminalScalingMode::Stretch,
             gutter: default_gutter_size(),
             auto_apply_batches: true,
+            linear_filtering: false,
         }
     }
 
@@ -148,6 +153,14 @@ impl BTermBuilder {
         self
     }
 
+    /// Request linear (smooth) texture filtering for font textures, instead
+    /// of the default nearest-neighbor sampling. Useful for antialiased fonts;
+    /// leave off for crisp pixel art.
+    pub fn with_linear_filtering(mut self, linear_filtering: bool) -> Self {
+        self.linear_filtering = linear_filtering;
+        self
+    }
+
     pub fn with_gutter(mut self, gutter: f32) -> Self {
         self.gutter = gutter;
         self
@@ -192,6 +205,7 @@ impl Plugin for BTermBuilder {
                 "bracket_term_diagnostics",
                 SystemStage::single_threaded(),
             );
+            app.insert_resource(BracketPerfStats::default());
             app.add_system(update_timing);
             app.add_system(update_mouse_position);
         }
@@ -204,9 +218,18 @@ impl Plugin for BTermBuilder {
             app.add_system(apply_all_batches);
         }
         app.add_system(update_consoles);
+        app.add_system(change_console_fonts);
         app.add_system(replace_meshes);
+        app.add_event::<crate::TerminalResized>();
         app.add_system(window_resize);
+        app.add_system(apply_scaling_mode);
         app.add_system(fix_images);
+        app.add_system(update_mouse_wheel);
+        app.add_system(update_keyboard);
+        app.add_system(update_gamepads);
+        app.add_system(update_clear_color);
+        app.add_system(update_cursor_visibility);
+        app.add_plugin(crate::scanlines::ScanlinesPlugin);
         if self.with_random_number_generator {
             app.insert_resource(RandomNumbers::new());
         }
@@ -6,11 +6,21 @@ use bevy::{
 #[derive(Resource)]
 pub(crate) struct ImagesToLoad(pub(crate) Vec<HandleUntyped>);
 
-pub(crate) fn fix_images(mut fonts: ResMut<ImagesToLoad>, mut images: ResMut<Assets<Image>>) {
+pub(crate) fn fix_images(
+    mut fonts: ResMut<ImagesToLoad>,
+    mut images: ResMut<Assets<Image>>,
+    builder: Res<crate::BTermBuilder>,
+) {
     if fonts.0.is_empty() {
         return;
     }
 
+    let filter_mode = if builder.linear_filtering {
+        bevy::render::render_resource::FilterMode::Linear
+    } else {
+        bevy::render::render_resource::FilterMode::Nearest
+    };
+
     for (handle, img) in images.iter_mut() {
         let mut to_remove = Vec::new();
         if let Some(i) = fonts.0.iter().enumerate().find(|(_i, h)| h.id == handle) {
@@ -19,9 +29,9 @@ pub(crate) fn fix_images(mut fonts: ResMut<ImagesToLoad>, mut images: ResMut<Ass
                 address_mode_u: bevy::render::render_resource::AddressMode::ClampToEdge,
                 address_mode_v: bevy::render::render_resource::AddressMode::ClampToEdge,
                 address_mode_w: bevy::render::render_resource::AddressMode::ClampToEdge,
-                mag_filter: bevy::render::render_resource::FilterMode::Nearest,
-                min_filter: bevy::render::render_resource::FilterMode::Nearest,
-                mipmap_filter: bevy::render::render_resource::FilterMode::Nearest,
+                mag_filter: filter_mode,
+                min_filter: filter_mode,
+                mipmap_filter: filter_mode,
                 ..Default::default()
             });
             to_remove.push(i.0);
@@ -3,7 +3,8 @@ mod simple_console;
 use bracket_geometry::prelude::{Point, Rect};
 pub(crate) use simple_console::*;
 mod update_system;
-use crate::{BracketContext, FontCharType};
+use crate::{fonts::FontStore, BracketContext, FontCharType};
+pub use update_system::{BracketPerfStats, TerminalResized};
 pub(crate) use update_system::*;
 mod sparse_console;
 pub(crate) use sparse_console::*;
@@ -36,6 +37,24 @@ pub(crate) trait ConsoleFrontEnd: Sync + Send {
     fn print_color_right(&mut self, x: i32, y: i32, fg: RGBA, bg: RGBA, text: &str);
     fn set(&mut self, x: i32, y: i32, fg: RGBA, bg: RGBA, glyph: FontCharType);
     fn set_bg(&mut self, x: i32, y: i32, bg: RGBA);
+
+    /// Queues a free-floating glyph with fractional position, rotation (in
+    /// radians, counter-clockwise around the glyph center) and per-axis
+    /// scale. Only sparse consoles render these; the default is a no-op.
+    fn set_fancy(
+        &mut self,
+        _position: (f32, f32),
+        _rotation: f32,
+        _scale: (f32, f32),
+        _fg: RGBA,
+        _bg: RGBA,
+        _glyph: FontCharType,
+    ) {
+    }
+
+    /// Reads back the glyph/colors at a cell, or None if it is out of
+    /// bounds (or, for sparse consoles, never written).
+    fn get_glyph(&self, x: i32, y: i32) -> Option<TerminalGlyph>;
     fn draw_box(&mut self, x: i32, y: i32, width: i32, height: i32, fg: RGBA, bg: RGBA);
     fn draw_hollow_box(&mut self, x: i32, y: i32, width: i32, height: i32, fg: RGBA, bg: RGBA);
 
@@ -128,6 +147,17 @@ pub(crate) trait ConsoleFrontEnd: Sync + Send {
     fn get_mouse_position_for_current_layer(&self) -> Point;
     fn set_mouse_position(&mut self, position: (f32, f32), scaler: &ScreenScaler);
     fn get_font_index(&self) -> usize;
+
+    /// Re-points the console at a different loaded font and recomputes the
+    /// grid dimensions for the new tile size. The default does nothing;
+    /// virtual consoles have no font.
+    fn change_font(&mut self, _font_index: usize, _fonts: &[FontStore], _scaler: &ScreenScaler) {}
+
+    /// Sets a whole-console alpha multiplier, applied to the vertex colors
+    /// when the mesh is rebuilt. 0.0 renders nothing, 1.0 renders the console
+    /// unchanged, and the cell data is left untouched. The default does
+    /// nothing; virtual consoles are never rendered directly.
+    fn set_alpha(&mut self, _alpha: f32) {}
 }
 
 #[derive(PartialEq, Copy, Clone, Debug)]
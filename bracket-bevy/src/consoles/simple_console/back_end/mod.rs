@@ -20,4 +20,5 @@ pub(crate) trait SimpleConsoleBackend: Sync + Send {
     fn spawn(&self, commands: &mut Commands, material: Handle<ColorMaterial>, idx: usize);
     fn get_pixel_size(&self) -> (f32, f32);
     fn resize(&mut self, available_size: &(f32, f32)) -> (i32, i32);
+    fn change_font(&mut self, chars_per_row: u16, n_rows: u16, font_height_pixels: (f32, f32));
 }
@@ -67,10 +67,12 @@ impl SimpleBackendNoBackground {
                 uv.push([tex[0], tex[1]]);
                 uv.push([tex[2], tex[1]]);
 
-                colors.push(parent.terminal[idx].foreground);
-                colors.push(parent.terminal[idx].foreground);
-                colors.push(parent.terminal[idx].foreground);
-                colors.push(parent.terminal[idx].foreground);
+                let mut foreground = parent.terminal[idx].foreground;
+                foreground[3] *= parent.alpha;
+                colors.push(foreground);
+                colors.push(foreground);
+                colors.push(foreground);
+                colors.push(foreground);
 
                 indices.push(index_count);
                 indices.push(index_count + 1);
@@ -127,4 +129,9 @@ impl SimpleConsoleBackend for SimpleBackendNoBackground {
 
         (self.width, self.height)
     }
+
+    fn change_font(&mut self, chars_per_row: u16, n_rows: u16, font_height_pixels: (f32, f32)) {
+        self.font_height_pixels = font_height_pixels;
+        self.scaler = FontScaler::new(chars_per_row, n_rows, font_height_pixels);
+    }
 }
@@ -23,6 +23,7 @@ pub(crate) struct SimpleConsole {
     back_end: Option<Box<dyn SimpleConsoleBackend>>,
     clipping: Option<Rect>,
     mouse_chars: (i32, i32),
+    pub(crate) alpha: f32,
 }
 
 impl SimpleConsole {
@@ -35,6 +36,7 @@ impl SimpleConsole {
             back_end: None,
             clipping: None,
             mouse_chars: (0, 0),
+            alpha: 1.0,
         }
     }
 
@@ -147,6 +149,10 @@ impl ConsoleFrontEnd for SimpleConsole {
         }
     }
 
+    fn get_glyph(&self, x: i32, y: i32) -> Option<TerminalGlyph> {
+        self.try_at(x, y).map(|idx| self.terminal[idx])
+    }
+
     fn print(&mut self, x: i32, y: i32, text: &str) {
         common_draw::print(self, x, y, text);
     }
@@ -311,4 +317,20 @@ impl ConsoleFrontEnd for SimpleConsole {
     fn get_font_index(&self) -> usize {
         self.font_index
     }
+
+    fn set_alpha(&mut self, alpha: f32) {
+        self.alpha = alpha;
+    }
+
+    fn change_font(&mut self, font_index: usize, fonts: &[FontStore], scaler: &ScreenScaler) {
+        self.font_index = font_index;
+        if let Some(back_end) = &mut self.back_end {
+            let font = &fonts[font_index];
+            back_end.change_font(font.chars_per_row, font.n_rows, font.font_height_pixels);
+            let (w, h) = back_end.resize(&scaler.available_size());
+            self.width = w;
+            self.height = h;
+            self.terminal = vec![TerminalGlyph::default(); (w * h) as usize];
+        }
+    }
 }
@@ -10,6 +10,31 @@ use bevy::{
 pub(crate) use sparse_no_background::*;
 pub(crate) use sparse_with_background::*;
 
+/// Computes the rotated/scaled screen-space corners of a fancy glyph's quad,
+/// turning around the glyph's center. Corner order matches the backends'
+/// vertex layout: bottom-left, bottom-right, top-left, top-right.
+pub(crate) fn fancy_quad_corners(
+    center: (f32, f32),
+    step: (f32, f32),
+    scale: (f32, f32),
+    rotation: f32,
+) -> [[f32; 2]; 4] {
+    let half = (step.0 * scale.0 * 0.5, step.1 * scale.1 * 0.5);
+    let (sin, cos) = rotation.sin_cos();
+    let corner = |cx: f32, cy: f32| {
+        [
+            center.0 + (cx * cos) - (cy * sin),
+            center.1 + (cx * sin) + (cy * cos),
+        ]
+    };
+    [
+        corner(-half.0, -half.1),
+        corner(half.0, -half.1),
+        corner(-half.0, half.1),
+        corner(half.0, half.1),
+    ]
+}
+
 pub(crate) trait SparseConsoleBackend: Sync + Send {
     fn new_mesh(
         &self,
@@ -20,4 +45,5 @@ pub(crate) trait SparseConsoleBackend: Sync + Send {
     fn spawn(&self, commands: &mut Commands, material: Handle<ColorMaterial>, idx: usize);
     fn get_pixel_size(&self) -> (f32, f32);
     fn resize(&mut self, available_size: &(f32, f32)) -> (i32, i32);
+    fn change_font(&mut self, chars_per_row: u16, n_rows: u16, font_height_pixels: (f32, f32));
 }
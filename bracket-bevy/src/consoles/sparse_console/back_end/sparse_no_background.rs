@@ -67,10 +67,47 @@ impl SparseBackendNoBackground {
             uv.push([tex[0], tex[1]]);
             uv.push([tex[2], tex[1]]);
 
-            colors.push(chr.foreground);
-            colors.push(chr.foreground);
-            colors.push(chr.foreground);
-            colors.push(chr.foreground);
+            let mut foreground = chr.foreground;
+            foreground[3] *= parent.alpha;
+            colors.push(foreground);
+            colors.push(foreground);
+            colors.push(foreground);
+            colors.push(foreground);
+
+            indices.push(index_count);
+            indices.push(index_count + 1);
+            indices.push(index_count + 2);
+
+            indices.push(index_count + 3);
+            indices.push(index_count + 2);
+            indices.push(index_count + 1);
+
+            index_count += 4;
+        }
+
+        for fancy in parent.fancy.iter() {
+            let actual_y = self.height as f32 - 1.0 - fancy.position.1;
+            let center = (
+                top_left.0 + ((fancy.position.0 + 0.5) * scale.0),
+                top_left.1 + ((actual_y + 0.5) * scale.1),
+            );
+            let corners = super::fancy_quad_corners(center, scale, fancy.scale, fancy.rotation);
+            for corner in corners {
+                vertices.push([corner[0], corner[1], 0.5]);
+                normals.push([0.0, 1.0, 0.0]);
+            }
+
+            let tex = self.scaler.texture_coords(fancy.glyph);
+            uv.push([tex[0], tex[3]]);
+            uv.push([tex[2], tex[3]]);
+            uv.push([tex[0], tex[1]]);
+            uv.push([tex[2], tex[1]]);
+
+            let mut foreground = fancy.foreground;
+            foreground[3] *= parent.alpha;
+            for _ in 0..4 {
+                colors.push(foreground);
+            }
 
             indices.push(index_count);
             indices.push(index_count + 1);
@@ -126,4 +163,9 @@ impl SparseConsoleBackend for SparseBackendNoBackground {
 
         (self.width, self.height)
     }
+
+    fn change_font(&mut self, chars_per_row: u16, n_rows: u16, font_height_pixels: (f32, f32)) {
+        self.font_height_pixels = font_height_pixels;
+        self.scaler = FontScaler::new(chars_per_row, n_rows, font_height_pixels);
+    }
 }
@@ -13,14 +13,27 @@ use bevy::{
 use bracket_color::prelude::RGBA;
 use bracket_geometry::prelude::Point;
 
+/// A free-floating glyph with sub-tile position, rotation and scale,
+/// rendered by the sparse console backends as a rotated quad.
+pub(crate) struct FancyGlyph {
+    pub(crate) position: (f32, f32),
+    pub(crate) rotation: f32,
+    pub(crate) scale: (f32, f32),
+    pub(crate) glyph: FontCharType,
+    pub(crate) foreground: [f32; 4],
+    pub(crate) background: [f32; 4],
+}
+
 pub(crate) struct SparseConsole {
     pub(crate) font_index: usize,
     pub(crate) width: i32,
     pub(crate) height: i32,
     pub(crate) terminal: Vec<(i32, i32, TerminalGlyph)>,
+    pub(crate) fancy: Vec<FancyGlyph>,
     back_end: Option<Box<dyn SparseConsoleBackend>>,
     clipping: Option<Rect>,
     mouse_chars: (i32, i32),
+    pub(crate) alpha: f32,
 }
 
 impl SparseConsole {
@@ -30,9 +43,11 @@ impl SparseConsole {
             width,
             height,
             terminal: Vec::new(),
+            fancy: Vec::new(),
             back_end: None,
             clipping: None,
             mouse_chars: (0, 0),
+            alpha: 1.0,
         }
     }
 
@@ -115,6 +130,7 @@ impl ConsoleFrontEnd for SparseConsole {
 
     fn cls(&mut self) {
         self.terminal.clear();
+        self.fancy.clear();
     }
 
     fn cls_bg(&mut self, color: RGBA) {
@@ -137,6 +153,33 @@ impl ConsoleFrontEnd for SparseConsole {
         }
     }
 
+    fn set_fancy(
+        &mut self,
+        position: (f32, f32),
+        rotation: f32,
+        scale: (f32, f32),
+        fg: RGBA,
+        bg: RGBA,
+        glyph: FontCharType,
+    ) {
+        self.fancy.push(FancyGlyph {
+            position,
+            rotation,
+            scale,
+            glyph,
+            foreground: fg.as_rgba_f32(),
+            background: bg.as_rgba_f32(),
+        });
+    }
+
+    fn get_glyph(&self, x: i32, y: i32) -> Option<TerminalGlyph> {
+        self.terminal
+            .iter()
+            .rev()
+            .find(|(tx, ty, _)| *tx == x && *ty == y)
+            .map(|(_, _, glyph)| *glyph)
+    }
+
     fn set_bg(&mut self, _x: i32, _y: i32, _bg: RGBA) {
         // Does nothing
     }
@@ -304,4 +347,20 @@ impl ConsoleFrontEnd for SparseConsole {
     fn get_font_index(&self) -> usize {
         self.font_index
     }
+
+    fn set_alpha(&mut self, alpha: f32) {
+        self.alpha = alpha;
+    }
+
+    fn change_font(&mut self, font_index: usize, fonts: &[FontStore], scaler: &ScreenScaler) {
+        self.font_index = font_index;
+        if let Some(back_end) = &mut self.back_end {
+            let font = &fonts[font_index];
+            back_end.change_font(font.chars_per_row, font.n_rows, font.font_height_pixels);
+            let (w, h) = back_end.resize(&scaler.available_size());
+            self.width = w;
+            self.height = h;
+            self.terminal.clear();
+        }
+    }
 }
@@ -2,6 +2,7 @@ use crate::{BracketCamera, BracketContext, TerminalScalingMode};
 use bevy::{
     diagnostic::{Diagnostics, FrameTimeDiagnosticsPlugin},
     ecs::event::Events,
+    input::mouse::MouseWheel,
     prelude::*,
     render::camera::RenderTarget,
     sprite::Mesh2dHandle,
@@ -14,13 +15,21 @@ pub(crate) fn update_consoles(
     mut ctx: ResMut<BracketContext>,
     mut meshes: ResMut<Assets<Mesh>>,
     find_mesh: Query<(&BracketMesh, &Mesh2dHandle)>,
+    mut visibility: Query<(&BracketMesh, &mut Visibility)>,
     scaler: Res<ScreenScaler>,
 ) {
+    visibility.for_each_mut(|(id, mut vis)| {
+        vis.is_visible = ctx.is_console_visible(id.0);
+    });
+
     let mut new_meshes: Vec<(Mesh2dHandle, Mesh2dHandle, bool)> = Vec::new();
     {
         let mut terms = ctx.terminals.lock();
         for (id, handle) in find_mesh.iter() {
             let terminal_id = id.0;
+            if !ctx.is_console_visible(terminal_id) {
+                continue;
+            }
             let new_mesh = terms[terminal_id].new_mesh(&ctx, &mut meshes, &scaler);
             if let Some(new_mesh) = new_mesh {
                 let old_mesh = handle.clone();
@@ -61,7 +70,21 @@ pub(crate) fn replace_meshes(
     ctx.mesh_replacement.retain(|(_, _, done)| !done);
 }
 
-pub(crate) fn update_timing(mut ctx: ResMut<BracketContext>, diagnostics: Res<Diagnostics>) {
+/// Per-frame performance numbers, published as a bevy `Resource` so
+/// diagnostics systems can read them without depending on `BracketContext`.
+/// The same values remain available as `BracketContext::fps` and
+/// `BracketContext::frame_time_ms` for back-compat.
+#[derive(Resource, Debug, Clone, Copy, Default)]
+pub struct BracketPerfStats {
+    pub fps: f64,
+    pub frame_time_ms: f64,
+}
+
+pub(crate) fn update_timing(
+    mut ctx: ResMut<BracketContext>,
+    mut perf_stats: ResMut<BracketPerfStats>,
+    diagnostics: Res<Diagnostics>,
+) {
     if let Some(fps_diagnostic) = diagnostics.get(FrameTimeDiagnosticsPlugin::FPS) {
         if let Some(fps_avg) = fps_diagnostic.measurement() {
             ctx.fps = fps_avg.value.round();
@@ -73,27 +96,174 @@ pub(crate) fn update_timing(mut ctx: ResMut<BracketContext>, diagnostics: Res<Di
             ctx.frame_time_ms = (frame_time_avg.value * 1000.0).round();
         }
     }
+
+    perf_stats.fps = ctx.fps;
+    perf_stats.frame_time_ms = ctx.frame_time_ms;
+}
+
+/// Event emitted when `TerminalScalingMode::ResizeTerminals` recomputes a
+/// console's grid after a window resize. Carries the new size in characters,
+/// so layout code can react instead of polling `get_char_size()`.
+#[derive(Debug, Clone, Copy)]
+pub struct TerminalResized {
+    pub console: usize,
+    pub width: i32,
+    pub height: i32,
 }
 
 pub(crate) fn window_resize(
     mut context: ResMut<BracketContext>,
     resize_event: Res<Events<WindowResized>>,
     mut scaler: ResMut<ScreenScaler>,
+    mut terminal_resized: EventWriter<TerminalResized>,
 ) {
     let mut reader = resize_event.get_reader();
     for e in reader.iter(&resize_event) {
         scaler.set_screen_size(e.width, e.height);
         if let TerminalScalingMode::ResizeTerminals = context.scaling_mode {
             context.resize_terminals(&scaler);
+            context
+                .terminals
+                .lock()
+                .iter()
+                .enumerate()
+                .for_each(|(console, term)| {
+                    let (width, height) = term.get_char_size();
+                    terminal_resized.send(TerminalResized {
+                        console,
+                        width,
+                        height,
+                    });
+                });
         }
         scaler.recalculate(context.get_pixel_size(), context.largest_font());
     }
 }
 
+pub(crate) fn change_console_fonts(
+    context: Res<BracketContext>,
+    scaler: Res<ScreenScaler>,
+    mut materials: Query<(&BracketMesh, &mut Handle<ColorMaterial>)>,
+) {
+    let requests = context.take_font_change_requests();
+    if requests.is_empty() {
+        return;
+    }
+    let mut terminals = context.terminals.lock();
+    for (console, font_index) in requests {
+        if console >= terminals.len() || font_index >= context.fonts.len() {
+            continue;
+        }
+        terminals[console].change_font(font_index, &context.fonts, &scaler);
+        materials.for_each_mut(|(id, mut material)| {
+            if id.0 == console {
+                *material = context.fonts[font_index].material_handle.clone();
+            }
+        });
+    }
+}
+
+pub(crate) fn apply_scaling_mode(
+    mut context: ResMut<BracketContext>,
+    mut scaler: ResMut<ScreenScaler>,
+    mut terminal_resized: EventWriter<TerminalResized>,
+) {
+    if !context.scaling_mode_changed {
+        return;
+    }
+    context.scaling_mode_changed = false;
+    if let TerminalScalingMode::ResizeTerminals = context.scaling_mode {
+        context.resize_terminals(&scaler);
+        context
+            .terminals
+            .lock()
+            .iter()
+            .enumerate()
+            .for_each(|(console, term)| {
+                let (width, height) = term.get_char_size();
+                terminal_resized.send(TerminalResized {
+                    console,
+                    width,
+                    height,
+                });
+            });
+        scaler.recalculate(context.get_pixel_size(), context.largest_font());
+    }
+}
+
+pub(crate) fn update_clear_color(
+    context: Res<BracketContext>,
+    mut clear_color: ResMut<ClearColor>,
+) {
+    if let Some(color) = context.take_clear_color_request() {
+        clear_color.0 = color.into();
+    }
+}
+
+pub(crate) fn update_cursor_visibility(
+    context: Res<BracketContext>,
+    mut windows: ResMut<Windows>,
+) {
+    if let Some(visible) = context.take_cursor_visible_request() {
+        if let Some(window) = windows.get_primary_mut() {
+            window.set_cursor_visibility(visible);
+        }
+    }
+}
+
 pub(crate) fn apply_all_batches(mut context: ResMut<BracketContext>) {
     context.render_all_batches();
 }
 
+pub(crate) fn update_keyboard(
+    keyboard: Res<Input<KeyCode>>,
+    mut context: ResMut<BracketContext>,
+) {
+    let pressed = keyboard
+        .get_pressed()
+        .filter_map(|key| crate::keycode_to_virtual(*key))
+        .collect();
+    context.set_pressed_keys(pressed);
+}
+
+pub(crate) fn update_gamepads(
+    gamepads: Res<Gamepads>,
+    buttons: Res<Input<GamepadButton>>,
+    axes: Res<Axis<GamepadAxis>>,
+    mut context: ResMut<BracketContext>,
+) {
+    let pressed = buttons
+        .get_pressed()
+        .map(|button| (button.gamepad.id, button.button_type))
+        .collect();
+
+    let mut axis_values = bevy::utils::HashMap::new();
+    for gamepad in gamepads.iter() {
+        for axis_type in [
+            GamepadAxisType::LeftStickX,
+            GamepadAxisType::LeftStickY,
+            GamepadAxisType::LeftZ,
+            GamepadAxisType::RightStickX,
+            GamepadAxisType::RightStickY,
+            GamepadAxisType::RightZ,
+        ] {
+            if let Some(value) = axes.get(GamepadAxis::new(*gamepad, axis_type)) {
+                axis_values.insert((gamepad.id, axis_type), value);
+            }
+        }
+    }
+    context.set_gamepad_state(pressed, axis_values);
+}
+
+pub(crate) fn update_mouse_wheel(
+    mut wheel_events: EventReader<MouseWheel>,
+    mut context: ResMut<BracketContext>,
+) {
+    for event in wheel_events.iter() {
+        context.add_mouse_wheel_delta(event.x, event.y);
+    }
+}
+
 pub(crate) fn update_mouse_position(
     wnds: Res<Windows>,
     q_camera: Query<(&Camera, &GlobalTransform), With<BracketCamera>>,
@@ -150,6 +150,10 @@ impl ConsoleFrontEnd for VirtualConsole {
         }
     }
 
+    fn get_glyph(&self, x: i32, y: i32) -> Option<TerminalGlyph> {
+        self.try_at(x, y).map(|idx| self.terminal[idx])
+    }
+
     fn print(&mut self, x: i32, y: i32, text: &str) {
         common_draw::print(self, x, y, text);
     }
@@ -1,11 +1,17 @@
 use crate::{
     consoles::{ConsoleFrontEnd, DrawBatch, DrawCommand, ScreenScaler},
     fonts::FontStore,
-    FontCharType, TerminalScalingMode,
+    scanlines::ScanlineSettings,
+    FontCharType, TerminalScalingMode, VirtualKeyCode,
 };
-use bevy::{sprite::Mesh2dHandle, utils::HashMap, prelude::Resource};
-use bracket_color::prelude::RGBA;
-use bracket_geometry::prelude::{Point, Rect};
+use bevy::{
+    prelude::{GamepadAxisType, GamepadButtonType, Resource},
+    sprite::Mesh2dHandle,
+    utils::HashMap,
+};
+use std::collections::HashSet;
+use bracket_color::prelude::{ColorPair, RGBA};
+use bracket_geometry::prelude::{BresenhamInclusive, Point, PointF, Radians, Rect};
 use parking_lot::Mutex;
 
 #[derive(Resource)]
@@ -18,8 +24,18 @@ pub struct BracketContext {
     pub frame_time_ms: f64,
     pub(crate) mesh_replacement: Vec<(Mesh2dHandle, Mesh2dHandle, bool)>,
     pub(crate) scaling_mode: TerminalScalingMode,
+    pub(crate) scaling_mode_changed: bool,
     command_buffers: Mutex<Vec<(usize, DrawBatch)>>,
     mouse_pixels: (f32, f32),
+    mouse_wheel: (f32, f32),
+    pressed_keys: HashSet<VirtualKeyCode>,
+    gamepad_buttons: HashSet<(usize, GamepadButtonType)>,
+    gamepad_axes: HashMap<(usize, GamepadAxisType), f32>,
+    clear_color_request: Mutex<Option<RGBA>>,
+    hidden_consoles: Mutex<HashSet<usize>>,
+    scanlines: Mutex<ScanlineSettings>,
+    font_change_requests: Mutex<Vec<(usize, usize)>>,
+    cursor_visible_request: Mutex<Option<bool>>,
 }
 
 impl BracketContext {
@@ -33,8 +49,18 @@ impl BracketContext {
             frame_time_ms: 0.0,
             mesh_replacement: Vec::new(),
             scaling_mode: TerminalScalingMode::Stretch,
+            scaling_mode_changed: false,
             command_buffers: Mutex::new(Vec::new()),
             mouse_pixels: (0.0, 0.0),
+            mouse_wheel: (0.0, 0.0),
+            pressed_keys: HashSet::new(),
+            gamepad_buttons: HashSet::new(),
+            gamepad_axes: HashMap::new(),
+            clear_color_request: Mutex::new(None),
+            hidden_consoles: Mutex::new(HashSet::new()),
+            scanlines: Mutex::new(ScanlineSettings::default()),
+            font_change_requests: Mutex::new(Vec::new()),
+            cursor_visible_request: Mutex::new(None),
         }
     }
 
@@ -94,6 +120,19 @@ impl BracketContext {
         self.terminals.lock()[self.current_layer()].set_clipping(clipping);
     }
 
+    /// Set the clipping rectangle on a specific console, without changing the
+    /// active layer. Print/draw calls outside the rectangle are dropped until
+    /// the clipping is cleared with `None`.
+    pub fn set_console_clipping(&self, console: usize, clip: Option<Rect>) {
+        self.terminals.lock()[console].set_clipping(clip);
+    }
+
+    /// Retrieve the clipping rectangle on a specific console, or None if
+    /// there isn't one.
+    pub fn get_console_clipping(&self, console: usize) -> Option<Rect> {
+        self.terminals.lock()[console].get_clipping()
+    }
+
     /// Set the current layer index.
     pub fn set_active_console(&self, layer: usize) {
         *self.current_layer.lock() = layer;
@@ -128,6 +167,72 @@ impl BracketContext {
         );
     }
 
+    /// Place a free-floating glyph at a fractional position on the current
+    /// layer, rotated (counter-clockwise around the glyph center) and scaled
+    /// per axis - the bevy equivalent of the native `set_fancy`. Only sparse
+    /// consoles render fancy glyphs; they draw on top of the regular cells
+    /// and are cleared with `cls`. Use for smooth sub-tile movement and
+    /// rotating projectiles.
+    pub fn set_fancy<G: TryInto<FontCharType>>(
+        &self,
+        position: PointF,
+        rotation: Radians,
+        scale: PointF,
+        color: ColorPair,
+        glyph: G,
+    ) {
+        self.terminals.lock()[self.current_layer()].set_fancy(
+            (position.x, position.y),
+            rotation.0,
+            (scale.x, scale.y),
+            color.fg,
+            color.bg,
+            glyph.try_into().ok().expect("Must be u16 convertible"),
+        );
+    }
+
+    /// Reads back the character and (foreground, background) colors at (x,y)
+    /// on the current layer, translating the glyph back through the CP437
+    /// table. Returns None if the cell is out of bounds, or was never written
+    /// on a sparse console.
+    pub fn get(&self, x: i32, y: i32) -> Option<(char, RGBA, RGBA)> {
+        self.terminals.lock()[self.current_layer()]
+            .get_glyph(x, y)
+            .map(|glyph| {
+                (
+                    crate::cp437::to_char(glyph.glyph as u8),
+                    glyph.foreground.into(),
+                    glyph.background.into(),
+                )
+            })
+    }
+
+    /// Dumps a console's glyphs as a string, one line per row (rows separated
+    /// with `\n`). Colors are discarded, and blank cells are rendered as
+    /// spaces. Mirrors the native `BTerm::to_text`, so golden tests can be
+    /// shared across backends.
+    pub fn to_text(&self, console: usize) -> String {
+        let terminals = self.terminals.lock();
+        let terminal = &terminals[console];
+        let (width, height) = terminal.get_char_size();
+
+        let mut result = String::with_capacity(((width + 1) * height) as usize);
+        for y in 0..height {
+            if y > 0 {
+                result.push('\n');
+            }
+            for x in 0..width {
+                let glyph = terminal.get_glyph(x, y).map_or(32, |cell| cell.glyph);
+                if glyph == 0 || glyph == 32 {
+                    result.push(' ');
+                } else {
+                    result.push(crate::cp437::to_char(glyph as u8));
+                }
+            }
+        }
+        result
+    }
+
     /// Set just the background color of a terminal cell.
     pub fn set_bg<POS: Into<i32>, C: Into<RGBA>>(&self, x: POS, y: POS, bg: C) {
         self.terminals.lock()[self.current_layer()].set_bg(x.into(), y.into(), bg.into());
@@ -330,16 +435,89 @@ impl BracketContext {
         );
     }
 
+    /// Draw a titled panel: a double-line box filling `bounds`, with `title`
+    /// printed centered on the top edge. Titles wider than the top edge are
+    /// truncated to fit (leaving the corner glyphs intact).
+    pub fn draw_panel<C: Into<RGBA>>(&self, bounds: Rect, title: &str, fg: C, bg: C) {
+        let fg = fg.into();
+        let bg = bg.into();
+        let mut terminals = self.terminals.lock();
+        let terminal = &mut terminals[self.current_layer()];
+        terminal.draw_box_double(bounds.x1, bounds.y1, bounds.width(), bounds.height(), fg, bg);
+
+        let interior = (bounds.width() - 1).max(0) as usize;
+        let title: String = title.chars().take(interior).collect();
+        if !title.is_empty() {
+            let x = bounds.x1 + (bounds.width() + 1) / 2 - (title.chars().count() as i32 / 2);
+            terminal.print_color(x, bounds.y1, &title, fg, bg);
+        }
+    }
+
+    /// Plot a line from `start` to `end` (inclusive) on the current layer,
+    /// walking it with Bresenham's algorithm and setting every visited cell
+    /// to the specified glyph and colors.
+    pub fn draw_line<C: Into<RGBA>>(&self, start: Point, end: Point, glyph: char, fg: C, bg: C) {
+        let fg = fg.into();
+        let bg = bg.into();
+        let glyph = crate::cp437::to_cp437(glyph);
+        let mut terminals = self.terminals.lock();
+        let terminal = &mut terminals[self.current_layer()];
+        for point in BresenhamInclusive::new(start, end) {
+            terminal.set(point.x, point.y, fg, bg, glyph);
+        }
+    }
+
     /// Fill a region specified by a rectangle with a specified glyph, and colors.
-    pub fn fill_region<C: Into<RGBA>>(&self, target: Rect, glyph: FontCharType, fg: C, bg: C) {
+    /// The region is clipped to the console bounds. The glyph accepts anything
+    /// convertible to a glyph index (including `char`), for parity with
+    /// `DrawBatch::fill_region`.
+    pub fn fill_region<C: Into<RGBA>, G: TryInto<FontCharType>>(
+        &self,
+        target: Rect,
+        glyph: G,
+        fg: C,
+        bg: C,
+    ) {
         self.terminals.lock()[self.current_layer()].fill_region(
             target,
-            glyph,
+            glyph.try_into().ok().expect("Must be u16 convertible"),
             fg.into(),
             bg.into(),
         );
     }
 
+    /// Paint-bucket fill on the current layer: replaces the contiguous region
+    /// of cells sharing the glyph under `start` (4-connected) with the given
+    /// glyph and colors. Does nothing if `start` is out of bounds, or on a
+    /// sparse console if the start cell was never written. Uses an explicit
+    /// queue, so deep regions can't overflow the stack.
+    pub fn flood_fill(&self, start: Point, glyph: char, color: ColorPair) {
+        let mut terminals = self.terminals.lock();
+        let terminal = &mut terminals[self.current_layer()];
+        let target = match terminal.get_glyph(start.x, start.y) {
+            Some(cell) => cell.glyph,
+            None => return,
+        };
+        let replacement = crate::cp437::to_cp437(glyph);
+
+        let mut visited: HashSet<(i32, i32)> = HashSet::new();
+        let mut open_list = vec![start];
+        while let Some(p) = open_list.pop() {
+            if !visited.insert((p.x, p.y)) {
+                continue;
+            }
+            match terminal.get_glyph(p.x, p.y) {
+                Some(cell) if cell.glyph == target => {}
+                _ => continue,
+            }
+            terminal.set(p.x, p.y, color.fg, color.bg, replacement);
+            open_list.push(Point::new(p.x - 1, p.y));
+            open_list.push(Point::new(p.x + 1, p.y));
+            open_list.push(Point::new(p.x, p.y - 1));
+            open_list.push(Point::new(p.x, p.y + 1));
+        }
+    }
+
     /// Draw a horizontal progress bar.
     #[allow(clippy::too_many_arguments)]
     pub fn draw_bar_horizontal<POS: Into<i32>, C: Into<RGBA>>(
@@ -401,6 +579,14 @@ impl BracketContext {
         self.terminals.lock()[self.current_layer()].set_all_alpha(fg, bg);
     }
 
+    /// Sets a whole-console alpha multiplier, applied to the vertex colors
+    /// when the console's mesh is rebuilt. 0.0 renders nothing, 1.0 renders
+    /// the console unchanged. Unlike `set_all_alpha`, this does not modify
+    /// the stored cell colors, so it can be animated freely.
+    pub fn set_console_alpha(&self, console: usize, alpha: f32) {
+        self.terminals.lock()[console].set_alpha(alpha);
+    }
+
     /// Retrieve a named color from the palette.
     /// Note that this replaces the `bracket_color` palette; there were performance problems
     /// using it on Bevy.
@@ -408,6 +594,23 @@ impl BracketContext {
         self.color_palette.get(color)
     }
 
+    /// Retrieve the current terminal scaling mode.
+    pub fn scaling_mode(&self) -> TerminalScalingMode {
+        self.scaling_mode
+    }
+
+    /// Change how terminals react to window resizing. `Stretch` keeps the
+    /// current grids and stretches the glyphs; `ResizeTerminals` recomputes
+    /// the grid dimensions to fit. Switching to `ResizeTerminals` recomputes
+    /// the grids immediately (emitting `TerminalResized` events); otherwise
+    /// the new mode takes effect on the next window resize.
+    pub fn set_scaling_mode(&mut self, scaling_mode: TerminalScalingMode) {
+        if self.scaling_mode != scaling_mode {
+            self.scaling_mode = scaling_mode;
+            self.scaling_mode_changed = true;
+        }
+    }
+
     pub(crate) fn resize_terminals(&mut self, scaler: &ScreenScaler) {
         let available_size = scaler.available_size();
         let mut lock = self.terminals.lock();
@@ -500,7 +703,7 @@ impl BracketContext {
                     color.bg,
                 ),
                 DrawCommand::FillRegion { pos, color, glyph } => {
-                    self.fill_region::<RGBA>(*pos, *glyph, color.fg, color.bg)
+                    self.fill_region(*pos, *glyph, color.fg, color.bg)
                 }
                 DrawCommand::BarHorizontal {
                     pos,
@@ -538,6 +741,125 @@ impl BracketContext {
         self.mouse_pixels
     }
 
+    /// Shows or hides a console layer. Hidden consoles keep their contents
+    /// and skip mesh regeneration; turning them back on restores the last
+    /// content without a rebuild.
+    pub fn set_console_visible(&self, console: usize, visible: bool) {
+        let mut hidden = self.hidden_consoles.lock();
+        if visible {
+            hidden.remove(&console);
+        } else {
+            hidden.insert(console);
+        }
+    }
+
+    /// Returns true if the specified console layer is currently visible.
+    pub fn is_console_visible(&self, console: usize) -> bool {
+        !self.hidden_consoles.lock().contains(&console)
+    }
+
+    /// Request that the window's clear/background color change, matching the
+    /// behavior of a native `cls_bg`. Applied by the renderer on the next
+    /// frame.
+    pub fn set_clear_color<C: Into<RGBA>>(&self, color: C) {
+        *self.clear_color_request.lock() = Some(color.into());
+    }
+
+    pub(crate) fn take_clear_color_request(&self) -> Option<RGBA> {
+        self.clear_color_request.lock().take()
+    }
+
+    /// Enable or disable the scanlines post-processing pass, matching the
+    /// native back-end's `post_scanlines` option. Applied by the renderer on
+    /// the next frame.
+    pub fn set_scanlines(&self, enabled: bool) {
+        self.scanlines.lock().enabled = enabled;
+    }
+
+    /// Enable or disable the screen-burn effect on unlit cells. Only visible
+    /// while scanlines are enabled, matching the native `post_screenburn`.
+    pub fn set_screen_burn(&self, enabled: bool) {
+        self.scanlines.lock().screen_burn = enabled;
+    }
+
+    /// Change the screen-burn glow color. Defaults to the same cyan as the
+    /// native back-ends.
+    pub fn set_screen_burn_color<C: Into<RGBA>>(&self, color: C) {
+        self.scanlines.lock().burn_color = color.into();
+    }
+
+    pub(crate) fn scanline_settings(&self) -> ScanlineSettings {
+        *self.scanlines.lock()
+    }
+
+    /// Re-points a console at a different loaded font (by `BTermBuilder` font
+    /// index). The console's grid dimensions recompute for the new tile size,
+    /// and its mesh is regenerated on the next frame. Out-of-range consoles
+    /// or fonts are ignored.
+    pub fn set_console_font(&self, console: usize, font_index: usize) {
+        self.font_change_requests.lock().push((console, font_index));
+    }
+
+    pub(crate) fn take_font_change_requests(&self) -> Vec<(usize, usize)> {
+        std::mem::take(&mut self.font_change_requests.lock())
+    }
+
+    /// Shows or hides the operating system mouse cursor over the primary
+    /// window, matching the native back-ends' `mouse_visible` flag. Applied
+    /// by the renderer on the next frame.
+    pub fn set_cursor_visible(&self, visible: bool) {
+        *self.cursor_visible_request.lock() = Some(visible);
+    }
+
+    pub(crate) fn take_cursor_visible_request(&self) -> Option<bool> {
+        self.cursor_visible_request.lock().take()
+    }
+
+    pub(crate) fn set_pressed_keys(&mut self, keys: HashSet<VirtualKeyCode>) {
+        self.pressed_keys = keys;
+    }
+
+    /// Returns true if the requested key is currently held down, using the
+    /// same `VirtualKeyCode` naming as the `bracket-terminal` back-ends.
+    pub fn key_pressed(&self, key: VirtualKeyCode) -> bool {
+        self.pressed_keys.contains(&key)
+    }
+
+    pub(crate) fn set_gamepad_state(
+        &mut self,
+        buttons: HashSet<(usize, GamepadButtonType)>,
+        axes: HashMap<(usize, GamepadAxisType), f32>,
+    ) {
+        self.gamepad_buttons = buttons;
+        self.gamepad_axes = axes;
+    }
+
+    /// Returns true if the given button on gamepad `id` is currently held
+    /// down. Gamepad ids match bevy's `Gamepad::id`; the first connected
+    /// pad is 0.
+    pub fn gamepad_button(&self, id: usize, button: GamepadButtonType) -> bool {
+        self.gamepad_buttons.contains(&(id, button))
+    }
+
+    /// Retrieve the current value of an analog axis on gamepad `id`, in
+    /// bevy's `-1.0..=1.0` range. Returns None if the pad isn't connected
+    /// or doesn't report that axis.
+    pub fn gamepad_axis(&self, id: usize, axis: GamepadAxisType) -> Option<f32> {
+        self.gamepad_axes.get(&(id, axis)).copied()
+    }
+
+    pub(crate) fn add_mouse_wheel_delta(&mut self, x: f32, y: f32) {
+        self.mouse_wheel.0 += x;
+        self.mouse_wheel.1 += y;
+    }
+
+    /// Retrieve the accumulated mouse wheel movement as (x, y). The delta
+    /// grows as the user scrolls; track the difference between reads if you
+    /// need per-frame movement.
+    pub fn mouse_wheel_delta(&self) -> (f32, f32) {
+        self.mouse_wheel
+    }
+
     pub fn get_mouse_position_for_current_layer(&self) -> Point {
         self.terminals.lock()[self.current_layer()].get_mouse_position_for_current_layer()
     }
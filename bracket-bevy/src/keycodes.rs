@@ -0,0 +1,369 @@
+use bevy::prelude::KeyCode;
+
+/// Keyboard key identifiers, matching the `VirtualKeyCode` enum used by the
+/// `bracket-terminal` back-ends. Using the same naming lets input-handling
+/// code be shared between native/wasm terminals and Bevy.
+#[derive(Debug, Hash, Ord, PartialOrd, PartialEq, Eq, Clone, Copy)]
+#[repr(u32)]
+pub enum VirtualKeyCode {
+    /// The '1' key over the letters.
+    Key1,
+    /// The '2' key over the letters.
+    Key2,
+    /// The '3' key over the letters.
+    Key3,
+    /// The '4' key over the letters.
+    Key4,
+    /// The '5' key over the letters.
+    Key5,
+    /// The '6' key over the letters.
+    Key6,
+    /// The '7' key over the letters.
+    Key7,
+    /// The '8' key over the letters.
+    Key8,
+    /// The '9' key over the letters.
+    Key9,
+    /// The '0' key over the 'O' and 'P' keys.
+    Key0,
+
+    A,
+    B,
+    C,
+    D,
+    E,
+    F,
+    G,
+    H,
+    I,
+    J,
+    K,
+    L,
+    M,
+    N,
+    O,
+    P,
+    Q,
+    R,
+    S,
+    T,
+    U,
+    V,
+    W,
+    X,
+    Y,
+    Z,
+
+    /// The Escape key, next to F1.
+    Escape,
+
+    F1,
+    F2,
+    F3,
+    F4,
+    F5,
+    F6,
+    F7,
+    F8,
+    F9,
+    F10,
+    F11,
+    F12,
+    F13,
+    F14,
+    F15,
+    F16,
+    F17,
+    F18,
+    F19,
+    F20,
+    F21,
+    F22,
+    F23,
+    F24,
+
+    /// Print Screen/SysRq.
+    Snapshot,
+    /// Scroll Lock.
+    Scroll,
+    /// Pause/Break key, next to Scroll lock.
+    Pause,
+
+    /// `Insert`, next to Backspace.
+    Insert,
+    Home,
+    Delete,
+    End,
+    PageDown,
+    PageUp,
+
+    Left,
+    Up,
+    Right,
+    Down,
+
+    /// The Backspace key, right over Enter.
+    Back,
+    /// The Enter key.
+    Return,
+    /// The space bar.
+    Space,
+
+    /// The "Compose" key on Linux.
+    Compose,
+
+    Caret,
+
+    Numlock,
+    Numpad0,
+    Numpad1,
+    Numpad2,
+    Numpad3,
+    Numpad4,
+    Numpad5,
+    Numpad6,
+    Numpad7,
+    Numpad8,
+    Numpad9,
+
+    AbntC1,
+    AbntC2,
+    Add,
+    Apostrophe,
+    Apps,
+    At,
+    Ax,
+    Backslash,
+    Calculator,
+    Capital,
+    Colon,
+    Comma,
+    Convert,
+    Decimal,
+    Divide,
+    Equals,
+    Grave,
+    Kana,
+    Kanji,
+    LAlt,
+    LBracket,
+    LControl,
+    LShift,
+    LWin,
+    Mail,
+    MediaSelect,
+    MediaStop,
+    Minus,
+    Multiply,
+    Mute,
+    MyComputer,
+    NavigateForward,  // also called "Prior"
+    NavigateBackward, // also called "Next"
+    NextTrack,
+    NoConvert,
+    NumpadComma,
+    NumpadEnter,
+    NumpadEquals,
+    OEM102,
+    Period,
+    PlayPause,
+    Power,
+    PrevTrack,
+    RAlt,
+    RBracket,
+    RControl,
+    RShift,
+    RWin,
+    Semicolon,
+    Slash,
+    Sleep,
+    Stop,
+    Subtract,
+    Sysrq,
+    Tab,
+    Underline,
+    Unlabeled,
+    VolumeDown,
+    VolumeUp,
+    Wake,
+    WebBack,
+    WebFavorites,
+    WebForward,
+    WebHome,
+    WebRefresh,
+    WebSearch,
+    WebStop,
+    Yen,
+    Copy,
+    Paste,
+    Cut,
+}
+
+/// Translates a Bevy `KeyCode` into the shared `VirtualKeyCode` naming.
+/// Returns `None` for keys that have no terminal equivalent.
+pub(crate) fn keycode_to_virtual(key: KeyCode) -> Option<VirtualKeyCode> {
+    match key {
+        KeyCode::Key1 => Some(VirtualKeyCode::Key1),
+        KeyCode::Key2 => Some(VirtualKeyCode::Key2),
+        KeyCode::Key3 => Some(VirtualKeyCode::Key3),
+        KeyCode::Key4 => Some(VirtualKeyCode::Key4),
+        KeyCode::Key5 => Some(VirtualKeyCode::Key5),
+        KeyCode::Key6 => Some(VirtualKeyCode::Key6),
+        KeyCode::Key7 => Some(VirtualKeyCode::Key7),
+        KeyCode::Key8 => Some(VirtualKeyCode::Key8),
+        KeyCode::Key9 => Some(VirtualKeyCode::Key9),
+        KeyCode::Key0 => Some(VirtualKeyCode::Key0),
+        KeyCode::A => Some(VirtualKeyCode::A),
+        KeyCode::B => Some(VirtualKeyCode::B),
+        KeyCode::C => Some(VirtualKeyCode::C),
+        KeyCode::D => Some(VirtualKeyCode::D),
+        KeyCode::E => Some(VirtualKeyCode::E),
+        KeyCode::F => Some(VirtualKeyCode::F),
+        KeyCode::G => Some(VirtualKeyCode::G),
+        KeyCode::H => Some(VirtualKeyCode::H),
+        KeyCode::I => Some(VirtualKeyCode::I),
+        KeyCode::J => Some(VirtualKeyCode::J),
+        KeyCode::K => Some(VirtualKeyCode::K),
+        KeyCode::L => Some(VirtualKeyCode::L),
+        KeyCode::M => Some(VirtualKeyCode::M),
+        KeyCode::N => Some(VirtualKeyCode::N),
+        KeyCode::O => Some(VirtualKeyCode::O),
+        KeyCode::P => Some(VirtualKeyCode::P),
+        KeyCode::Q => Some(VirtualKeyCode::Q),
+        KeyCode::R => Some(VirtualKeyCode::R),
+        KeyCode::S => Some(VirtualKeyCode::S),
+        KeyCode::T => Some(VirtualKeyCode::T),
+        KeyCode::U => Some(VirtualKeyCode::U),
+        KeyCode::V => Some(VirtualKeyCode::V),
+        KeyCode::W => Some(VirtualKeyCode::W),
+        KeyCode::X => Some(VirtualKeyCode::X),
+        KeyCode::Y => Some(VirtualKeyCode::Y),
+        KeyCode::Z => Some(VirtualKeyCode::Z),
+        KeyCode::Escape => Some(VirtualKeyCode::Escape),
+        KeyCode::F1 => Some(VirtualKeyCode::F1),
+        KeyCode::F2 => Some(VirtualKeyCode::F2),
+        KeyCode::F3 => Some(VirtualKeyCode::F3),
+        KeyCode::F4 => Some(VirtualKeyCode::F4),
+        KeyCode::F5 => Some(VirtualKeyCode::F5),
+        KeyCode::F6 => Some(VirtualKeyCode::F6),
+        KeyCode::F7 => Some(VirtualKeyCode::F7),
+        KeyCode::F8 => Some(VirtualKeyCode::F8),
+        KeyCode::F9 => Some(VirtualKeyCode::F9),
+        KeyCode::F10 => Some(VirtualKeyCode::F10),
+        KeyCode::F11 => Some(VirtualKeyCode::F11),
+        KeyCode::F12 => Some(VirtualKeyCode::F12),
+        KeyCode::F13 => Some(VirtualKeyCode::F13),
+        KeyCode::F14 => Some(VirtualKeyCode::F14),
+        KeyCode::F15 => Some(VirtualKeyCode::F15),
+        KeyCode::F16 => Some(VirtualKeyCode::F16),
+        KeyCode::F17 => Some(VirtualKeyCode::F17),
+        KeyCode::F18 => Some(VirtualKeyCode::F18),
+        KeyCode::F19 => Some(VirtualKeyCode::F19),
+        KeyCode::F20 => Some(VirtualKeyCode::F20),
+        KeyCode::F21 => Some(VirtualKeyCode::F21),
+        KeyCode::F22 => Some(VirtualKeyCode::F22),
+        KeyCode::F23 => Some(VirtualKeyCode::F23),
+        KeyCode::F24 => Some(VirtualKeyCode::F24),
+        KeyCode::Snapshot => Some(VirtualKeyCode::Snapshot),
+        KeyCode::Scroll => Some(VirtualKeyCode::Scroll),
+        KeyCode::Pause => Some(VirtualKeyCode::Pause),
+        KeyCode::Insert => Some(VirtualKeyCode::Insert),
+        KeyCode::Home => Some(VirtualKeyCode::Home),
+        KeyCode::Delete => Some(VirtualKeyCode::Delete),
+        KeyCode::End => Some(VirtualKeyCode::End),
+        KeyCode::PageDown => Some(VirtualKeyCode::PageDown),
+        KeyCode::PageUp => Some(VirtualKeyCode::PageUp),
+        KeyCode::Left => Some(VirtualKeyCode::Left),
+        KeyCode::Up => Some(VirtualKeyCode::Up),
+        KeyCode::Right => Some(VirtualKeyCode::Right),
+        KeyCode::Down => Some(VirtualKeyCode::Down),
+        KeyCode::Back => Some(VirtualKeyCode::Back),
+        KeyCode::Return => Some(VirtualKeyCode::Return),
+        KeyCode::Space => Some(VirtualKeyCode::Space),
+        KeyCode::Compose => Some(VirtualKeyCode::Compose),
+        KeyCode::Caret => Some(VirtualKeyCode::Caret),
+        KeyCode::Numlock => Some(VirtualKeyCode::Numlock),
+        KeyCode::Numpad0 => Some(VirtualKeyCode::Numpad0),
+        KeyCode::Numpad1 => Some(VirtualKeyCode::Numpad1),
+        KeyCode::Numpad2 => Some(VirtualKeyCode::Numpad2),
+        KeyCode::Numpad3 => Some(VirtualKeyCode::Numpad3),
+        KeyCode::Numpad4 => Some(VirtualKeyCode::Numpad4),
+        KeyCode::Numpad5 => Some(VirtualKeyCode::Numpad5),
+        KeyCode::Numpad6 => Some(VirtualKeyCode::Numpad6),
+        KeyCode::Numpad7 => Some(VirtualKeyCode::Numpad7),
+        KeyCode::Numpad8 => Some(VirtualKeyCode::Numpad8),
+        KeyCode::Numpad9 => Some(VirtualKeyCode::Numpad9),
+        KeyCode::AbntC1 => Some(VirtualKeyCode::AbntC1),
+        KeyCode::AbntC2 => Some(VirtualKeyCode::AbntC2),
+        KeyCode::NumpadAdd => Some(VirtualKeyCode::Add),
+        KeyCode::Apostrophe => Some(VirtualKeyCode::Apostrophe),
+        KeyCode::Apps => Some(VirtualKeyCode::Apps),
+        KeyCode::At => Some(VirtualKeyCode::At),
+        KeyCode::Ax => Some(VirtualKeyCode::Ax),
+        KeyCode::Backslash => Some(VirtualKeyCode::Backslash),
+        KeyCode::Calculator => Some(VirtualKeyCode::Calculator),
+        KeyCode::Capital => Some(VirtualKeyCode::Capital),
+        KeyCode::Colon => Some(VirtualKeyCode::Colon),
+        KeyCode::Comma => Some(VirtualKeyCode::Comma),
+        KeyCode::Convert => Some(VirtualKeyCode::Convert),
+        KeyCode::NumpadDecimal => Some(VirtualKeyCode::Decimal),
+        KeyCode::NumpadDivide => Some(VirtualKeyCode::Divide),
+        KeyCode::Equals => Some(VirtualKeyCode::Equals),
+        KeyCode::Grave => Some(VirtualKeyCode::Grave),
+        KeyCode::Kana => Some(VirtualKeyCode::Kana),
+        KeyCode::Kanji => Some(VirtualKeyCode::Kanji),
+        KeyCode::LAlt => Some(VirtualKeyCode::LAlt),
+        KeyCode::LBracket => Some(VirtualKeyCode::LBracket),
+        KeyCode::LControl => Some(VirtualKeyCode::LControl),
+        KeyCode::LShift => Some(VirtualKeyCode::LShift),
+        KeyCode::LWin => Some(VirtualKeyCode::LWin),
+        KeyCode::Mail => Some(VirtualKeyCode::Mail),
+        KeyCode::MediaSelect => Some(VirtualKeyCode::MediaSelect),
+        KeyCode::MediaStop => Some(VirtualKeyCode::MediaStop),
+        KeyCode::Minus => Some(VirtualKeyCode::Minus),
+        KeyCode::NumpadMultiply => Some(VirtualKeyCode::Multiply),
+        KeyCode::Mute => Some(VirtualKeyCode::Mute),
+        KeyCode::MyComputer => Some(VirtualKeyCode::MyComputer),
+        KeyCode::NavigateForward => Some(VirtualKeyCode::NavigateForward),
+        KeyCode::NavigateBackward => Some(VirtualKeyCode::NavigateBackward),
+        KeyCode::NextTrack => Some(VirtualKeyCode::NextTrack),
+        KeyCode::NoConvert => Some(VirtualKeyCode::NoConvert),
+        KeyCode::NumpadComma => Some(VirtualKeyCode::NumpadComma),
+        KeyCode::NumpadEnter => Some(VirtualKeyCode::NumpadEnter),
+        KeyCode::NumpadEquals => Some(VirtualKeyCode::NumpadEquals),
+        KeyCode::Oem102 => Some(VirtualKeyCode::OEM102),
+        KeyCode::Period => Some(VirtualKeyCode::Period),
+        KeyCode::PlayPause => Some(VirtualKeyCode::PlayPause),
+        KeyCode::Power => Some(VirtualKeyCode::Power),
+        KeyCode::PrevTrack => Some(VirtualKeyCode::PrevTrack),
+        KeyCode::RAlt => Some(VirtualKeyCode::RAlt),
+        KeyCode::RBracket => Some(VirtualKeyCode::RBracket),
+        KeyCode::RControl => Some(VirtualKeyCode::RControl),
+        KeyCode::RShift => Some(VirtualKeyCode::RShift),
+        KeyCode::RWin => Some(VirtualKeyCode::RWin),
+        KeyCode::Semicolon => Some(VirtualKeyCode::Semicolon),
+        KeyCode::Slash => Some(VirtualKeyCode::Slash),
+        KeyCode::Sleep => Some(VirtualKeyCode::Sleep),
+        KeyCode::Stop => Some(VirtualKeyCode::Stop),
+        KeyCode::NumpadSubtract => Some(VirtualKeyCode::Subtract),
+        KeyCode::Sysrq => Some(VirtualKeyCode::Sysrq),
+        KeyCode::Tab => Some(VirtualKeyCode::Tab),
+        KeyCode::Underline => Some(VirtualKeyCode::Underline),
+        KeyCode::Unlabeled => Some(VirtualKeyCode::Unlabeled),
+        KeyCode::VolumeDown => Some(VirtualKeyCode::VolumeDown),
+        KeyCode::VolumeUp => Some(VirtualKeyCode::VolumeUp),
+        KeyCode::Wake => Some(VirtualKeyCode::Wake),
+        KeyCode::WebBack => Some(VirtualKeyCode::WebBack),
+        KeyCode::WebFavorites => Some(VirtualKeyCode::WebFavorites),
+        KeyCode::WebForward => Some(VirtualKeyCode::WebForward),
+        KeyCode::WebHome => Some(VirtualKeyCode::WebHome),
+        KeyCode::WebRefresh => Some(VirtualKeyCode::WebRefresh),
+        KeyCode::WebSearch => Some(VirtualKeyCode::WebSearch),
+        KeyCode::WebStop => Some(VirtualKeyCode::WebStop),
+        KeyCode::Yen => Some(VirtualKeyCode::Yen),
+        KeyCode::Copy => Some(VirtualKeyCode::Copy),
+        KeyCode::Paste => Some(VirtualKeyCode::Paste),
+        KeyCode::Cut => Some(VirtualKeyCode::Cut),
+        _ => None,
+    }
+}
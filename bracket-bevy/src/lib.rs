@@ -5,19 +5,31 @@ mod fonts;
 pub use builder::*;
 mod context;
 pub use context::*;
+mod keycodes;
+pub use keycodes::VirtualKeyCode;
+pub(crate) use keycodes::keycode_to_virtual;
 mod consoles;
 use consoles::*;
 mod random_resource;
-pub use consoles::{DrawBatch, VirtualConsole};
+mod scanlines;
+pub use consoles::{BracketPerfStats, DrawBatch, TerminalResized, VirtualConsole};
 pub use random_resource::*;
 mod textblock;
 
 pub type FontCharType = u16;
 
+/// The bevy-side equivalent of `bracket-terminal`'s `DrawBatch`, named to
+/// make the correspondence obvious when sharing drawing code between the
+/// two backends. Obtain one with `BracketContext::new_draw_batch`, queue
+/// commands (`print`, `set`, `draw_box`, `target`, ...) and hand it back
+/// with `BracketContext::submit_batch`; batches are applied in z-order by
+/// the end-of-frame `apply_all_batches` system.
+pub type BracketDrawBatch = DrawBatch;
+
 pub mod prelude {
     pub use crate::{
-        consoles::TextAlign, cp437::*, textblock::*, BTermBuilder, BracketContext, DrawBatch,
-        RandomNumbers, TerminalScalingMode, VirtualConsole,
+        consoles::TextAlign, cp437::*, textblock::*, BTermBuilder, BracketContext, BracketDrawBatch,
+        DrawBatch, RandomNumbers, TerminalScalingMode, VirtualConsole, VirtualKeyCode,
     };
     pub use bracket_color::prelude::*;
     pub use bracket_geometry::prelude::*;
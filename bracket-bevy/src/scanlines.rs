@@ -0,0 +1,215 @@
+//! Optional scanlines/screen-burn post-processing, mirroring the
+//! `post_scanlines` option from the native `bracket-terminal` back-end.
+//! When enabled, the Bracket camera is re-targeted at an off-screen image,
+//! and a full-screen quad re-renders that image through a port of the
+//! native scanlines shader.
+
+use crate::{BracketCamera, BracketContext};
+use bevy::{
+    asset::load_internal_asset,
+    prelude::*,
+    reflect::TypeUuid,
+    render::{
+        camera::RenderTarget,
+        render_resource::{
+            AsBindGroup, Extent3d, ShaderRef, TextureDescriptor, TextureDimension, TextureFormat,
+            TextureUsages,
+        },
+        view::RenderLayers,
+    },
+    sprite::{Material2d, Material2dPlugin, MaterialMesh2dBundle},
+};
+use bracket_color::prelude::RGBA;
+
+pub(crate) const SCANLINES_SHADER_HANDLE: HandleUntyped =
+    HandleUntyped::weak_from_u64(Shader::TYPE_UUID, 0x1c4d_9f27_ab33_52e1);
+
+/// The render layer used for the post-processing pass, keeping the
+/// full-screen quad invisible to the main Bracket camera.
+const POST_PROCESSING_LAYER: u8 = 15;
+
+/// Current scanline post-processing state, stored on the context so user
+/// code can toggle it with `&self` access.
+#[derive(Clone, Copy)]
+pub(crate) struct ScanlineSettings {
+    pub(crate) enabled: bool,
+    pub(crate) screen_burn: bool,
+    pub(crate) burn_color: RGBA,
+}
+
+impl Default for ScanlineSettings {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            screen_burn: false,
+            // Matches the native back-ends' default screen burn color.
+            burn_color: RGBA::from_f32(0.0, 1.0, 1.0, 1.0),
+        }
+    }
+}
+
+/// Material applying the scanlines shader to the off-screen render of the
+/// terminal layers. The uniforms mirror the native shader: screen size in
+/// pixels, a screen-burn toggle and the burn color.
+#[derive(AsBindGroup, TypeUuid, Debug, Clone)]
+#[uuid = "4e12dcef-3b50-4f02-a80c-7d9c51d7a965"]
+pub(crate) struct ScanlinesMaterial {
+    #[uniform(0)]
+    pub(crate) screen_size: Vec2,
+    #[uniform(0)]
+    pub(crate) screen_burn: f32,
+    #[uniform(0)]
+    pub(crate) burn_color: Color,
+    #[texture(1)]
+    #[sampler(2)]
+    pub(crate) source_image: Handle<Image>,
+}
+
+impl Material2d for ScanlinesMaterial {
+    fn fragment_shader() -> ShaderRef {
+        SCANLINES_SHADER_HANDLE.typed().into()
+    }
+}
+
+#[derive(Component)]
+pub(crate) struct ScanlinesOverlay;
+
+#[derive(Component)]
+pub(crate) struct ScanlinesCamera;
+
+pub(crate) struct ScanlinesPlugin;
+
+impl Plugin for ScanlinesPlugin {
+    fn build(&self, app: &mut App) {
+        load_internal_asset!(
+            app,
+            SCANLINES_SHADER_HANDLE,
+            "scanlines.wgsl",
+            Shader::from_wgsl
+        );
+        app.add_plugin(Material2dPlugin::<ScanlinesMaterial>::default());
+        app.add_system(update_scanlines);
+    }
+}
+
+fn render_target_image(width: u32, height: u32) -> Image {
+    let size = Extent3d {
+        width,
+        height,
+        ..Default::default()
+    };
+    let mut image = Image {
+        texture_descriptor: TextureDescriptor {
+            label: None,
+            size,
+            dimension: TextureDimension::D2,
+            format: TextureFormat::Bgra8UnormSrgb,
+            mip_level_count: 1,
+            sample_count: 1,
+            usage: TextureUsages::TEXTURE_BINDING
+                | TextureUsages::COPY_DST
+                | TextureUsages::RENDER_ATTACHMENT,
+        },
+        ..Default::default()
+    };
+    image.resize(size);
+    image
+}
+
+/// Builds, updates or tears down the post-processing pass to match the
+/// context's scanline settings.
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn update_scanlines(
+    mut commands: Commands,
+    context: Res<BracketContext>,
+    windows: Res<Windows>,
+    mut images: ResMut<Assets<Image>>,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<ScanlinesMaterial>>,
+    mut main_camera: Query<&mut Camera, With<BracketCamera>>,
+    mut overlay: Query<
+        (Entity, &Handle<ScanlinesMaterial>, &mut Transform),
+        With<ScanlinesOverlay>,
+    >,
+    post_camera: Query<Entity, With<ScanlinesCamera>>,
+) {
+    let window = if let Some(window) = windows.get_primary() {
+        window
+    } else {
+        return;
+    };
+    let settings = context.scanline_settings();
+
+    if settings.enabled && overlay.is_empty() {
+        // Redirect the terminal render into an off-screen image...
+        let image_handle =
+            images.add(render_target_image(window.physical_width(), window.physical_height()));
+        for mut camera in main_camera.iter_mut() {
+            camera.target = RenderTarget::Image(image_handle.clone());
+        }
+
+        // ...and re-render it through the scanlines shader with a
+        // full-screen quad on its own layer/camera.
+        let material_handle = materials.add(ScanlinesMaterial {
+            screen_size: Vec2::new(window.width(), window.height()),
+            screen_burn: if settings.screen_burn { 1.0 } else { 0.0 },
+            burn_color: settings.burn_color.into(),
+            source_image: image_handle,
+        });
+        commands.spawn((
+            MaterialMesh2dBundle {
+                mesh: meshes.add(Mesh::from(shape::Quad::new(Vec2::ONE))).into(),
+                material: material_handle,
+                transform: Transform::from_scale(Vec3::new(window.width(), window.height(), 1.0)),
+                ..Default::default()
+            },
+            RenderLayers::layer(POST_PROCESSING_LAYER),
+            ScanlinesOverlay,
+        ));
+        commands.spawn((
+            Camera2dBundle {
+                camera: Camera {
+                    priority: 1,
+                    ..Default::default()
+                },
+                ..Default::default()
+            },
+            RenderLayers::layer(POST_PROCESSING_LAYER),
+            ScanlinesCamera,
+        ));
+    } else if settings.enabled {
+        // Keep the uniforms and target size in sync with the window.
+        for (_, material_handle, mut transform) in overlay.iter_mut() {
+            transform.scale = Vec3::new(window.width(), window.height(), 1.0);
+            if let Some(material) = materials.get_mut(material_handle) {
+                material.screen_size = Vec2::new(window.width(), window.height());
+                material.screen_burn = if settings.screen_burn { 1.0 } else { 0.0 };
+                material.burn_color = settings.burn_color.into();
+                if let Some(image) = images.get_mut(&material.source_image) {
+                    let target_size = Extent3d {
+                        width: window.physical_width(),
+                        height: window.physical_height(),
+                        ..Default::default()
+                    };
+                    if image.texture_descriptor.size != target_size {
+                        image.resize(target_size);
+                    }
+                }
+            }
+        }
+    } else if !overlay.is_empty() {
+        // Scanlines were switched off: restore direct-to-window rendering.
+        for (entity, material_handle, _) in overlay.iter() {
+            if let Some(material) = materials.get(material_handle) {
+                images.remove(&material.source_image);
+            }
+            commands.entity(entity).despawn();
+        }
+        for entity in post_camera.iter() {
+            commands.entity(entity).despawn();
+        }
+        for mut camera in main_camera.iter_mut() {
+            camera.target = RenderTarget::default();
+        }
+    }
+}
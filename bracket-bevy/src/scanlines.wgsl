@@ -0,0 +1,43 @@
+// Port of the native bracket-terminal scanlines/screen-burn shader
+// (SCANLINES_FS) to WGSL, applied as a full-screen post-processing pass.
+
+#import bevy_sprite::mesh2d_view_bindings
+#import bevy_pbr::utils
+
+struct ScanlinesMaterial {
+    screen_size: vec2<f32>,
+    screen_burn: f32,
+    burn_color: vec4<f32>,
+};
+
+@group(1) @binding(0)
+var<uniform> material: ScanlinesMaterial;
+@group(1) @binding(1)
+var source_image: texture_2d<f32>;
+@group(1) @binding(2)
+var source_sampler: sampler;
+
+fn random(p: vec2<f32>) -> f32 {
+    return fract(cos(dot(p, vec2<f32>(23.14069263277926, 2.665144142690225))) * 12345.6789);
+}
+
+@fragment
+fn fragment(
+    @builtin(position) position: vec4<f32>,
+    #import bevy_sprite::mesh2d_vertex_output
+) -> @location(0) vec4<f32> {
+    let uv = coords_to_viewport_uv(position.xy, view.viewport);
+    let col = textureSample(source_image, source_sampler, uv).rgb;
+    let scan_line = (position.y % 2.0) * 0.25;
+    let scan_color = col - vec3<f32>(scan_line);
+
+    if (col.r < 0.1 && col.g < 0.1 && col.b < 0.1) {
+        if (material.screen_burn > 0.5) {
+            let noise = 64.0 * (0.5 - random(uv)) / material.screen_size.x;
+            let dist = (1.0 - distance(uv, vec2<f32>(0.5, 0.5)) + noise) * 0.2;
+            return vec4<f32>(material.burn_color.rgb * dist, 1.0);
+        }
+        return vec4<f32>(0.0, 0.0, 0.0, 1.0);
+    }
+    return vec4<f32>(scan_color, 1.0);
+}
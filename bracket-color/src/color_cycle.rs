@@ -0,0 +1,78 @@
+use crate::prelude::RGBA;
+
+/// A repeating color animation for classic palette-cycling effects, such as
+/// shimmering water tiles. Holds a list of colors and a cycling speed;
+/// sample it with the running time (e.g. `BTerm::run_time_seconds`) to get
+/// the current color.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, PartialEq)]
+pub struct ColorCycle {
+    /// The colors to cycle through, in order. The cycle loops back to the
+    /// first color after the last.
+    pub colors: Vec<RGBA>,
+    /// How many colors the cycle advances per second.
+    pub speed: f32,
+}
+
+impl ColorCycle {
+    /// Creates a color cycle from a list of colors and a speed, expressed
+    /// in colors-per-second.
+    #[must_use]
+    pub fn new(colors: Vec<RGBA>, speed: f32) -> Self {
+        Self { colors, speed }
+    }
+
+    /// Returns the color for the given time, smoothly interpolating between
+    /// adjacent colors and wrapping around the loop. An empty cycle returns
+    /// transparent black; a single-color cycle always returns that color.
+    #[must_use]
+    #[allow(clippy::cast_precision_loss)]
+    #[allow(clippy::cast_possible_truncation)]
+    #[allow(clippy::cast_sign_loss)]
+    pub fn sample(&self, time: f32) -> RGBA {
+        if self.colors.is_empty() {
+            return RGBA::from_f32(0.0, 0.0, 0.0, 0.0);
+        }
+        let n = self.colors.len();
+        if n == 1 {
+            return self.colors[0];
+        }
+        let position = (time * self.speed).rem_euclid(n as f32);
+        let index = position as usize % n;
+        let fraction = position.fract();
+        self.colors[index].lerp(self.colors[(index + 1) % n], fraction)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::{ColorCycle, RGBA};
+
+    #[test]
+    fn test_sample_endpoints_and_wrap() {
+        let red = RGBA::from_f32(1.0, 0.0, 0.0, 1.0);
+        let blue = RGBA::from_f32(0.0, 0.0, 1.0, 1.0);
+        // One color per second.
+        let cycle = ColorCycle::new(vec![red, blue], 1.0);
+        assert_eq!(cycle.sample(0.0), red);
+        assert_eq!(cycle.sample(1.0), blue);
+        // The loop wraps back to the start.
+        assert_eq!(cycle.sample(2.0), red);
+        // Halfway between the two colors.
+        let mid = cycle.sample(0.5);
+        assert!(f32::abs(mid.r - 0.5) < std::f32::EPSILON);
+        assert!(f32::abs(mid.b - 0.5) < std::f32::EPSILON);
+    }
+
+    #[test]
+    fn test_sample_degenerate() {
+        let empty = ColorCycle::new(Vec::new(), 1.0);
+        assert!(empty.sample(10.0).a < std::f32::EPSILON);
+        let red = RGBA::from_f32(1.0, 0.0, 0.0, 1.0);
+        let single = ColorCycle::new(vec![red], 5.0);
+        assert_eq!(single.sample(3.7), red);
+        // Negative time still lands inside the loop.
+        let pair = ColorCycle::new(vec![red, red], 1.0);
+        assert_eq!(pair.sample(-0.25), red);
+    }
+}
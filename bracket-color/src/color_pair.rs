@@ -31,6 +31,36 @@ impl ColorPair {
             bg: bg.into(),
         }
     }
+
+    #[inline]
+    #[must_use]
+    /// Creates a new `ColorPair` from a foreground color, defaulting the
+    /// background to transparent black.
+    ///
+    /// # Arguments
+    ///
+    /// * `fg` - The foreground color to use.
+    pub fn from_fg<COLOR: Into<RGBA>>(fg: COLOR) -> Self {
+        Self {
+            fg: fg.into(),
+            bg: RGBA::from_f32(0.0, 0.0, 0.0, 0.0),
+        }
+    }
+
+    #[inline]
+    #[must_use]
+    /// Creates a new `ColorPair` from a background color, defaulting the
+    /// foreground to transparent black.
+    ///
+    /// # Arguments
+    ///
+    /// * `bg` - The background color to use.
+    pub fn from_bg<COLOR: Into<RGBA>>(bg: COLOR) -> Self {
+        Self {
+            fg: RGBA::from_f32(0.0, 0.0, 0.0, 0.0),
+            bg: bg.into(),
+        }
+    }
 }
 
 #[cfg(test)]
@@ -64,4 +94,17 @@ mod tests {
         assert!(cp.bg.b < std::f32::EPSILON);
         assert!(cp.bg.a < std::f32::EPSILON);
     }
+
+    #[test]
+    // Tests that single-color constructors default the other side to
+    // transparent black.
+    fn make_single_color_pairs() {
+        let cp = ColorPair::from_fg(RGB::named(WHITE));
+        assert_eq!(cp.fg, RGBA::from_f32(1.0, 1.0, 1.0, 1.0));
+        assert_eq!(cp.bg, RGBA::from_f32(0.0, 0.0, 0.0, 0.0));
+
+        let cp = ColorPair::from_bg(RGB::named(WHITE));
+        assert_eq!(cp.fg, RGBA::from_f32(0.0, 0.0, 0.0, 0.0));
+        assert_eq!(cp.bg, RGBA::from_f32(1.0, 1.0, 1.0, 1.0));
+    }
 }
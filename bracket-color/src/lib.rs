@@ -30,6 +30,8 @@
 #[macro_use]
 extern crate lazy_static;
 
+/// Import color cycling support
+pub mod color_cycle;
 /// Import color pair support
 pub mod color_pair;
 /// Import HSV color support
@@ -48,6 +50,7 @@ pub mod rgba;
 
 /// Exports the color functions/types in the `prelude` namespace.
 pub mod prelude {
+    pub use crate::color_cycle::*;
     pub use crate::color_pair::*;
     pub use crate::hsv::*;
     pub use crate::lerpit::*;
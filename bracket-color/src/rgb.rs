@@ -23,6 +23,8 @@ pub enum HtmlColorConversionError {
     MissingHash,
     /// An unexpected character (not #, A-F) was detected in the color string.
     InvalidCharacter,
+    /// The string wasn't in a recognized CSS color notation.
+    UnknownFormat,
 }
 
 // Implement operator overloading
@@ -218,6 +220,47 @@ impl RGB {
         Self::from_u8(col.0, col.1, col.2)
     }
 
+    /// Constructs an RGB color from a blackbody color temperature in Kelvin,
+    /// using Tanner Helland's approximation. Useful for day/night lighting
+    /// tints: low temperatures are warm orange (candlelight is around
+    /// 1800K), 6500K is near neutral white, and high temperatures are cool
+    /// blue. Input is clamped to the supported 1000-40000K range.
+    ///
+    /// # Arguments
+    ///
+    /// * `kelvin` - the color temperature in Kelvin (clamped to 1000..40000)
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bracket_color::prelude::*;
+    /// let candle = RGB::from_temperature(1800.0);
+    /// let daylight = RGB::from_temperature(6500.0);
+    /// ```
+    #[must_use]
+    pub fn from_temperature(kelvin: f32) -> Self {
+        let t = f32::min(40_000.0, f32::max(1000.0, kelvin)) / 100.0;
+        let r = if t <= 66.0 {
+            255.0
+        } else {
+            329.698_727_446 * (t - 60.0).powf(-0.133_204_759_2)
+        };
+        let g = if t <= 66.0 {
+            (99.470_802_586_1 * t.ln()) - 161.119_568_166_1
+        } else {
+            288.122_169_528_3 * (t - 60.0).powf(-0.075_514_849_2)
+        };
+        let b = if t >= 66.0 {
+            255.0
+        } else if t <= 19.0 {
+            0.0
+        } else {
+            (138.517_731_223_1 * (t - 10.0).ln()) - 305.044_792_730_7
+        };
+        // from_f32 clamps each channel into 0..1.
+        Self::from_f32(r / 255.0, g / 255.0, b / 255.0)
+    }
+
     /// Constructs from an HTML color code (e.g. "#eeffee")
     /// 
     /// # Arguments
@@ -457,6 +500,28 @@ mod crossterm_features {
 mod tests {
     use crate::prelude::*;
 
+    #[test]
+    // Tests the color-temperature approximation at reference points.
+    fn test_from_temperature() {
+        // 6500K is near neutral white.
+        let neutral = RGB::from_temperature(6500.0);
+        assert!(neutral.r > 0.95);
+        assert!(neutral.g > 0.9);
+        assert!(neutral.b > 0.9);
+        // Low temperatures are warm (red-heavy, little blue).
+        let candle = RGB::from_temperature(1800.0);
+        assert!(candle.r > candle.g && candle.g > candle.b);
+        // High temperatures are cool (blue-heavy).
+        let sky = RGB::from_temperature(20_000.0);
+        assert!(sky.b > sky.r);
+        // Out-of-range input clamps rather than misbehaving.
+        assert_eq!(RGB::from_temperature(0.0), RGB::from_temperature(1000.0));
+        assert_eq!(
+            RGB::from_temperature(100_000.0),
+            RGB::from_temperature(40_000.0)
+        );
+    }
+
     #[test]
     // Tests that we make an RGB triplet at defaults and it is black.
     fn make_rgb_minimal() {
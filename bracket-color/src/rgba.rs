@@ -31,16 +31,19 @@ impl ops::Add<f32> for RGBA {
     }
 }
 
-/// Support adding an RGB to a color. The result is clamped via the constructor.
+/// Support adding an RGBA to a color, channel-wise (including alpha).
+/// The result is clamped to the 0..1 range, so additive blends such as
+/// `base * 0.5 + glow` cannot overflow.
 impl ops::Add<RGBA> for RGBA {
     type Output = Self;
     #[must_use]
-    fn add(mut self, rhs: Self) -> Self {
-        self.r += rhs.r;
-        self.g += rhs.g;
-        self.b += rhs.b;
-        self.a += rhs.a;
-        self
+    fn add(self, rhs: Self) -> Self {
+        Self::from_f32(
+            self.r + rhs.r,
+            self.g + rhs.g,
+            self.b + rhs.b,
+            self.a + rhs.a,
+        )
     }
 }
 
@@ -70,16 +73,14 @@ impl ops::Sub<RGBA> for RGBA {
     }
 }
 
-/// Support multiplying a color by a float. The result is clamped via the constructor.
+/// Support multiplying a color by a float. All four channels are scaled,
+/// including alpha; multiply `to_rgb()` instead if you need the alpha
+/// preserved. The result is clamped to the 0..1 range.
 impl ops::Mul<f32> for RGBA {
     type Output = Self;
     #[must_use]
-    fn mul(mut self, rhs: f32) -> Self {
-        self.r *= rhs;
-        self.g *= rhs;
-        self.b *= rhs;
-        self.a *= rhs;
-        self
+    fn mul(self, rhs: f32) -> Self {
+        Self::from_f32(self.r * rhs, self.g * rhs, self.b * rhs, self.a * rhs)
     }
 }
 
@@ -287,6 +288,88 @@ impl RGBA {
         })
     }
 
+    /// Constructs from a CSS color string. Accepts 8-digit hex (`#RRGGBBAA`),
+    /// 6-digit hex (`#RRGGBB`, alpha defaults to 1.0), and functional
+    /// `rgb(r, g, b)` / `rgba(r, g, b, a)` notation. In functional notation
+    /// the color channels are 0-255 integers or percentages, and the alpha
+    /// channel is a 0..1 float or a percentage.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bracket_color::prelude::*;
+    /// let red = RGBA::from_css("#FF0000FF").unwrap();
+    /// let also_red = RGBA::from_css("rgba(255, 0, 0, 1.0)").unwrap();
+    /// assert_eq!(red, also_red);
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// See `HtmlColorConversionError`
+    pub fn from_css<S: AsRef<str>>(code: S) -> Result<Self, HtmlColorConversionError> {
+        let code = code.as_ref().trim();
+        if code.starts_with('#') {
+            return match Self::from_hex(code) {
+                Ok(rgba) => Ok(rgba),
+                Err(HtmlColorConversionError::InvalidStringLength) => {
+                    RGB::from_hex(code).map(|rgb| rgb.to_rgba(1.0))
+                }
+                Err(e) => Err(e),
+            };
+        }
+
+        let (body, has_alpha) = if let Some(rest) = code.strip_prefix("rgba(") {
+            (rest, true)
+        } else if let Some(rest) = code.strip_prefix("rgb(") {
+            (rest, false)
+        } else {
+            return Err(HtmlColorConversionError::UnknownFormat);
+        };
+        let body = body
+            .strip_suffix(')')
+            .ok_or(HtmlColorConversionError::UnknownFormat)?;
+
+        let channels: Vec<&str> = body.split(',').map(str::trim).collect();
+        if channels.len() != if has_alpha { 4 } else { 3 } {
+            return Err(HtmlColorConversionError::InvalidStringLength);
+        }
+
+        // Color channels are 0-255 values, or percentages.
+        let mut rgb = [0.0f32; 3];
+        for (slot, channel) in rgb.iter_mut().zip(&channels) {
+            *slot = if let Some(pct) = channel.strip_suffix('%') {
+                pct.trim()
+                    .parse::<f32>()
+                    .map_err(|_| HtmlColorConversionError::InvalidCharacter)?
+                    / 100.0
+            } else {
+                channel
+                    .parse::<f32>()
+                    .map_err(|_| HtmlColorConversionError::InvalidCharacter)?
+                    / 255.0
+            };
+        }
+
+        // Alpha is a 0..1 float, or a percentage.
+        let alpha = if has_alpha {
+            let channel = channels[3];
+            if let Some(pct) = channel.strip_suffix('%') {
+                pct.trim()
+                    .parse::<f32>()
+                    .map_err(|_| HtmlColorConversionError::InvalidCharacter)?
+                    / 100.0
+            } else {
+                channel
+                    .parse::<f32>()
+                    .map_err(|_| HtmlColorConversionError::InvalidCharacter)?
+            }
+        } else {
+            1.0
+        };
+
+        Ok(Self::from_f32(rgb[0], rgb[1], rgb[2], alpha))
+    }
+
     /// Converts to an RGB, dropping the alpha component
     #[inline]
     #[must_use]
@@ -318,6 +401,95 @@ impl RGBA {
         hsv.to_rgb().to_rgba(self.a)
     }
 
+    /// Rotates the color's hue by `degrees` via HSV, preserving saturation,
+    /// value and alpha. The hue wraps around the color wheel.
+    #[inline]
+    #[must_use]
+    fn rotate_hue(&self, degrees: f32) -> Self {
+        let mut hsv = self.to_rgb().to_hsv();
+        hsv.h = (hsv.h + degrees / 360.0).rem_euclid(1.0);
+        hsv.to_rgb().to_rgba(self.a)
+    }
+
+    /// Returns the complementary color: the hue rotated 180 degrees around
+    /// the color wheel via HSV, preserving saturation, value and alpha.
+    #[inline]
+    #[must_use]
+    pub fn complementary(&self) -> Self {
+        self.rotate_hue(180.0)
+    }
+
+    /// Returns the two analogous colors, with hues rotated `degrees` to
+    /// either side of this color on the HSV color wheel. Saturation, value
+    /// and alpha are preserved. A common choice is 30 degrees.
+    #[inline]
+    #[must_use]
+    pub fn analogous(&self, degrees: f32) -> (Self, Self) {
+        (self.rotate_hue(-degrees), self.rotate_hue(degrees))
+    }
+
+    /// Returns the two remaining colors of a triadic scheme: hues rotated
+    /// 120 degrees to either side of this color via HSV, preserving
+    /// saturation, value and alpha.
+    #[inline]
+    #[must_use]
+    pub fn triadic(&self) -> (Self, Self) {
+        (self.rotate_hue(120.0), self.rotate_hue(-120.0))
+    }
+
+    /// Returns the palette entry closest to this color, measured by Euclidean
+    /// distance in RGB space (alpha is ignored for matching, and the palette
+    /// entry's alpha is returned). An empty palette returns the input color
+    /// unchanged.
+    #[must_use]
+    pub fn nearest_in_palette(&self, palette: &[Self]) -> Self {
+        let mut best = *self;
+        let mut best_distance = f32::MAX;
+        for entry in palette {
+            let dr = self.r - entry.r;
+            let dg = self.g - entry.g;
+            let db = self.b - entry.b;
+            let distance = (dr * dr) + (dg * dg) + (db * db);
+            if distance < best_distance {
+                best_distance = distance;
+                best = *entry;
+            }
+        }
+        best
+    }
+
+    /// Converts to premultiplied alpha, multiplying each color channel by
+    /// the alpha channel. The crate's colors are normally straight
+    /// (non-premultiplied) alpha; use this when your compositing math
+    /// expects premultiplied inputs.
+    #[inline]
+    #[must_use]
+    pub fn premultiply(&self) -> Self {
+        Self {
+            r: self.r * self.a,
+            g: self.g * self.a,
+            b: self.b * self.a,
+            a: self.a,
+        }
+    }
+
+    /// Converts from premultiplied alpha back to the crate's normal straight
+    /// alpha, dividing each color channel by the alpha channel. A zero alpha
+    /// returns transparent black rather than dividing by zero.
+    #[inline]
+    #[must_use]
+    pub fn unpremultiply(&self) -> Self {
+        if self.a == 0.0 {
+            return Self::from_f32(0.0, 0.0, 0.0, 0.0);
+        }
+        Self {
+            r: self.r / self.a,
+            g: self.g / self.a,
+            b: self.b / self.a,
+            a: self.a,
+        }
+    }
+
     /// Lerps by a specified percentage (from 0 to 1) between this color and another
     #[inline]
     #[must_use]
@@ -481,6 +653,24 @@ mod crossterm_features {
 mod tests {
     use crate::prelude::*;
 
+    #[test]
+    // Tests premultiply/unpremultiply round-tripping, and the zero-alpha case.
+    fn test_premultiply() {
+        let col = RGBA::from_f32(0.5, 1.0, 0.25, 0.5);
+        let pre = col.premultiply();
+        assert!(f32::abs(pre.r - 0.25) < std::f32::EPSILON);
+        assert!(f32::abs(pre.g - 0.5) < std::f32::EPSILON);
+        assert!(f32::abs(pre.b - 0.125) < std::f32::EPSILON);
+        assert!(f32::abs(pre.a - 0.5) < std::f32::EPSILON);
+        let straight = pre.unpremultiply();
+        assert!(f32::abs(straight.r - col.r) < std::f32::EPSILON);
+        assert!(f32::abs(straight.g - col.g) < std::f32::EPSILON);
+        assert!(f32::abs(straight.b - col.b) < std::f32::EPSILON);
+        let zero = RGBA::from_f32(1.0, 1.0, 1.0, 0.0).premultiply().unpremultiply();
+        assert!(zero.r < std::f32::EPSILON);
+        assert!(zero.a < std::f32::EPSILON);
+    }
+
     #[test]
     // Tests that we make an RGB triplet at defaults and it is black.
     fn make_rgba_minimal() {
@@ -542,6 +732,98 @@ mod tests {
         assert!((rgb.a - 1.0).abs() < std::f32::EPSILON);
     }
 
+    #[test]
+    // Tests palette quantization
+    fn test_nearest_in_palette() {
+        let palette = [
+            RGBA::named(BLACK),
+            RGBA::named(RED),
+            RGBA::named(GREEN),
+            RGBA::named(WHITE),
+        ];
+        let dark_red = RGBA::from_u8(200, 30, 20, 255);
+        assert_eq!(dark_red.nearest_in_palette(&palette), RGBA::named(RED));
+        let near_white = RGBA::from_u8(240, 250, 245, 255);
+        assert_eq!(near_white.nearest_in_palette(&palette), RGBA::named(WHITE));
+
+        // An empty palette returns the input unchanged.
+        assert_eq!(dark_red.nearest_in_palette(&[]), dark_red);
+    }
+
+    #[test]
+    // Tests hue-rotation color schemes
+    fn test_color_schemes() {
+        let close = |a: RGBA, b: RGBA| {
+            f32::abs(a.r - b.r) < 0.01
+                && f32::abs(a.g - b.g) < 0.01
+                && f32::abs(a.b - b.b) < 0.01
+                && f32::abs(a.a - b.a) < 0.01
+        };
+
+        // Red's complement is cyan; rotating twice returns to red.
+        let red = RGBA::from_f32(1.0, 0.0, 0.0, 1.0);
+        assert!(close(red.complementary(), RGBA::from_f32(0.0, 1.0, 1.0, 1.0)));
+        assert!(close(red.complementary().complementary(), red));
+
+        // A red triad is green and blue.
+        let (t1, t2) = red.triadic();
+        assert!(close(t1, RGBA::from_f32(0.0, 1.0, 0.0, 1.0)));
+        assert!(close(t2, RGBA::from_f32(0.0, 0.0, 1.0, 1.0)));
+
+        // Analogous colors sit 30 degrees to either side: rose and orange.
+        let (a1, a2) = red.analogous(30.0);
+        assert!(close(a1, RGBA::from_f32(1.0, 0.0, 0.5, 1.0)));
+        assert!(close(a2, RGBA::from_f32(1.0, 0.5, 0.0, 1.0)));
+
+        // Alpha is preserved through rotation.
+        let faded = RGBA::from_f32(0.0, 1.0, 0.0, 0.5);
+        assert!(f32::abs(faded.complementary().a - 0.5) < std::f32::EPSILON);
+    }
+
+    #[test]
+    // Tests the CSS color string parser
+    fn test_from_css() {
+        let hex8 = RGBA::from_css("#FF000080").unwrap();
+        assert!(f32::abs(hex8.r - 1.0) < std::f32::EPSILON);
+        assert!(f32::abs(hex8.a - 128.0 / 255.0) < std::f32::EPSILON);
+
+        let hex6 = RGBA::from_css("#00FF00").unwrap();
+        assert!(f32::abs(hex6.g - 1.0) < std::f32::EPSILON);
+        assert!(f32::abs(hex6.a - 1.0) < std::f32::EPSILON);
+
+        let rgba = RGBA::from_css("rgba(255, 0, 0, 0.5)").unwrap();
+        assert!(f32::abs(rgba.r - 1.0) < std::f32::EPSILON);
+        assert!(f32::abs(rgba.a - 0.5) < std::f32::EPSILON);
+
+        let rgb = RGBA::from_css("rgb(0, 255, 0)").unwrap();
+        assert!(f32::abs(rgb.g - 1.0) < std::f32::EPSILON);
+        assert!(f32::abs(rgb.a - 1.0) < std::f32::EPSILON);
+
+        let pct = RGBA::from_css("rgba(100%, 0%, 50%, 50%)").unwrap();
+        assert!(f32::abs(pct.r - 1.0) < std::f32::EPSILON);
+        assert!(f32::abs(pct.b - 0.5) < std::f32::EPSILON);
+        assert!(f32::abs(pct.a - 0.5) < std::f32::EPSILON);
+
+        assert!(RGBA::from_css("hsl(0, 100%, 50%)").is_err());
+        assert!(RGBA::from_css("rgba(255, 0, 0)").is_err());
+        assert!(RGBA::from_css("rgb(cat, 0, 0)").is_err());
+    }
+
+    #[test]
+    // Tests that additive blending clamps rather than overflowing
+    fn test_add_and_scale_clamp() {
+        let base = RGBA::from_f32(0.8, 0.8, 0.8, 1.0);
+        let glow = RGBA::from_f32(0.5, 0.1, 0.0, 1.0);
+        let lit = base * 0.5 + glow;
+        assert!(f32::abs(lit.r - 0.9) < std::f32::EPSILON);
+        assert!(f32::abs(lit.g - 0.5) < std::f32::EPSILON);
+        assert!(f32::abs(lit.b - 0.4) < std::f32::EPSILON);
+
+        let overflow = RGBA::from_f32(1.0, 1.0, 1.0, 1.0) + RGBA::from_f32(1.0, 1.0, 1.0, 1.0);
+        assert!(f32::abs(overflow.r - 1.0) < std::f32::EPSILON);
+        assert!(f32::abs(overflow.a - 1.0) < std::f32::EPSILON);
+    }
+
     #[test]
     // Test the lerp function
     fn test_lerp() {
@@ -0,0 +1,194 @@
+use crate::prelude::PointF;
+
+/// Samples a uniform Catmull-Rom spline passing through `points`, with `t` in
+/// `[0, 1]` spanning the whole path (`t = 0` is the first point, `t = 1` the
+/// last). The spline interpolates every control point; end segments clamp the
+/// missing neighbor to the nearest endpoint. With fewer than four control
+/// points this falls back to linear interpolation along the polyline, and an
+/// empty slice returns the origin.
+pub fn catmull_rom(points: &[PointF], t: f32) -> PointF {
+    match points.len() {
+        0 => PointF::new(0.0, 0.0),
+        1 => points[0],
+        _ => {
+            let t = t.clamp(0.0, 1.0);
+            let n_segments = points.len() - 1;
+            let scaled = t * n_segments as f32;
+            let segment = usize::min(scaled as usize, n_segments - 1);
+            let u = scaled - segment as f32;
+
+            if points.len() < 4 {
+                // Not enough neighbors for a spline: lerp along the polyline.
+                let a = points[segment];
+                let b = points[segment + 1];
+                return a + ((b - a) * u);
+            }
+
+            let p0 = points[segment.saturating_sub(1)];
+            let p1 = points[segment];
+            let p2 = points[segment + 1];
+            let p3 = points[usize::min(segment + 2, points.len() - 1)];
+
+            let u2 = u * u;
+            let u3 = u2 * u;
+            ((p1 * 2.0)
+                + ((p2 - p0) * u)
+                + ((p0 * 2.0 - p1 * 5.0 + p2 * 4.0 - p3) * u2)
+                + ((p1 * 3.0 - p0 - p2 * 3.0 + p3) * u3))
+                * 0.5
+        }
+    }
+}
+
+/// Evaluates a quadratic Bézier curve from `p0` to `p2` with control point
+/// `p1`, at `t` in `[0, 1]`. `t = 0` returns `p0` and `t = 1` returns `p2`
+/// exactly, so chained segments connect without floating-point seams.
+pub fn bezier_quadratic(p0: PointF, p1: PointF, p2: PointF, t: f32) -> PointF {
+    let t = t.clamp(0.0, 1.0);
+    if t == 0.0 {
+        return p0;
+    }
+    if t == 1.0 {
+        return p2;
+    }
+    let u = 1.0 - t;
+    (p0 * (u * u)) + (p1 * (2.0 * u * t)) + (p2 * (t * t))
+}
+
+/// Evaluates a cubic Bézier curve from `p0` to `p3` with control points `p1`
+/// and `p2`, at `t` in `[0, 1]`. As with [`bezier_quadratic`], the endpoints
+/// are returned exactly at `t = 0` and `t = 1`.
+pub fn bezier_cubic(p0: PointF, p1: PointF, p2: PointF, p3: PointF, t: f32) -> PointF {
+    let t = t.clamp(0.0, 1.0);
+    if t == 0.0 {
+        return p0;
+    }
+    if t == 1.0 {
+        return p3;
+    }
+    let u = 1.0 - t;
+    let u2 = u * u;
+    let t2 = t * t;
+    (p0 * (u2 * u)) + (p1 * (3.0 * u2 * t)) + (p2 * (3.0 * u * t2)) + (p3 * (t2 * t))
+}
+
+/// Samples a parametric curve at `steps + 1` evenly-spaced `t` values from
+/// `0` to `1` inclusive, returning the points in order. Useful for turning
+/// any of the curve functions in this module into a polyline. `steps = 0`
+/// returns just the point at `t = 0`.
+pub fn sample_curve<F: Fn(f32) -> PointF>(curve: F, steps: usize) -> Vec<PointF> {
+    (0..=steps)
+        .map(|i| {
+            let t = if steps == 0 {
+                0.0
+            } else {
+                i as f32 / steps as f32
+            };
+            curve(t)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::{bezier_cubic, bezier_quadratic, catmull_rom, sample_curve, PointF};
+
+    fn close(a: PointF, b: PointF) -> bool {
+        f32::abs(a.x - b.x) < 0.0001 && f32::abs(a.y - b.y) < 0.0001
+    }
+
+    #[test]
+    fn spline_hits_endpoints() {
+        let points = vec![
+            PointF::new(0.0, 0.0),
+            PointF::new(1.0, 2.0),
+            PointF::new(3.0, 2.0),
+            PointF::new(4.0, 0.0),
+        ];
+        assert!(close(catmull_rom(&points, 0.0), points[0]));
+        assert!(close(catmull_rom(&points, 1.0), points[3]));
+    }
+
+    #[test]
+    fn spline_passes_through_control_points() {
+        let points = vec![
+            PointF::new(0.0, 0.0),
+            PointF::new(1.0, 2.0),
+            PointF::new(3.0, 2.0),
+            PointF::new(4.0, 0.0),
+        ];
+        // Interior control points sit at evenly-spaced t values.
+        assert!(close(catmull_rom(&points, 1.0 / 3.0), points[1]));
+        assert!(close(catmull_rom(&points, 2.0 / 3.0), points[2]));
+    }
+
+    #[test]
+    fn linear_fallback() {
+        let points = vec![PointF::new(0.0, 0.0), PointF::new(10.0, 0.0)];
+        assert!(close(catmull_rom(&points, 0.5), PointF::new(5.0, 0.0)));
+
+        let three = vec![
+            PointF::new(0.0, 0.0),
+            PointF::new(10.0, 0.0),
+            PointF::new(10.0, 10.0),
+        ];
+        assert!(close(catmull_rom(&three, 0.5), PointF::new(10.0, 0.0)));
+    }
+
+    #[test]
+    fn degenerate_inputs() {
+        assert!(close(catmull_rom(&[], 0.5), PointF::new(0.0, 0.0)));
+        let single = vec![PointF::new(3.0, 4.0)];
+        assert!(close(catmull_rom(&single, 0.5), single[0]));
+    }
+
+    #[test]
+    fn bezier_endpoints_exact() {
+        let p0 = PointF::new(0.1, 0.2);
+        let p1 = PointF::new(1.7, 3.3);
+        let p2 = PointF::new(4.9, 0.6);
+        let p3 = PointF::new(6.2, 2.8);
+        // Exact equality, not approximate: chained segments must connect.
+        assert_eq!(bezier_quadratic(p0, p1, p2, 0.0), p0);
+        assert_eq!(bezier_quadratic(p0, p1, p2, 1.0), p2);
+        assert_eq!(bezier_cubic(p0, p1, p2, p3, 0.0), p0);
+        assert_eq!(bezier_cubic(p0, p1, p2, p3, 1.0), p3);
+    }
+
+    #[test]
+    fn bezier_midpoints() {
+        // Quadratic midpoint is the average of the endpoint midpoint and the
+        // control point.
+        let mid = bezier_quadratic(
+            PointF::new(0.0, 0.0),
+            PointF::new(2.0, 4.0),
+            PointF::new(4.0, 0.0),
+            0.5,
+        );
+        assert!(close(mid, PointF::new(2.0, 2.0)));
+
+        // A cubic with collinear control points stays on the line.
+        let mid = bezier_cubic(
+            PointF::new(0.0, 0.0),
+            PointF::new(1.0, 1.0),
+            PointF::new(2.0, 2.0),
+            PointF::new(3.0, 3.0),
+            0.5,
+        );
+        assert!(close(mid, PointF::new(1.5, 1.5)));
+    }
+
+    #[test]
+    fn sample_curve_steps() {
+        let p0 = PointF::new(0.0, 0.0);
+        let p1 = PointF::new(5.0, 5.0);
+        let p2 = PointF::new(10.0, 0.0);
+        let samples = sample_curve(|t| bezier_quadratic(p0, p1, p2, t), 4);
+        assert_eq!(samples.len(), 5);
+        assert_eq!(samples[0], p0);
+        assert_eq!(samples[4], p2);
+
+        let degenerate = sample_curve(|t| bezier_quadratic(p0, p1, p2, t), 0);
+        assert_eq!(degenerate, vec![p0]);
+    }
+}
@@ -6,7 +6,8 @@ use std::cmp::{max, min};
 pub enum DistanceAlg {
     /// Use the Pythagoras algorithm for determining distance - sqrt(A^2 + B^2)
     Pythagoras,
-    /// Us the Pythagoras algorithm for distance, but omitting the square-root for a faster but squared result.
+    /// Use the Pythagoras algorithm for distance, but omitting the square-root for a faster but squared result.
+    /// Prefer this over `Pythagoras` when only the ordering of distances matters (e.g. radius checks).
     PythagorasSquared,
     /// Use Manhattan distance (distance up plus distance along)
     Manhattan,
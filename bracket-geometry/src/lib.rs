@@ -65,12 +65,14 @@
 mod angle;
 mod angles;
 mod circle_bresenham;
+mod curves;
 mod distance;
 mod line_bresenham;
 mod line_vector;
 mod lines;
 mod point;
 mod point3;
+mod polygon;
 mod rect;
 mod rectf;
 
@@ -79,12 +81,14 @@ pub mod prelude {
     pub use crate::angle::*;
     pub use crate::angles::*;
     pub use crate::circle_bresenham::*;
+    pub use crate::curves::*;
     pub use crate::distance::*;
     pub use crate::line_bresenham::*;
     pub use crate::line_vector::*;
     pub use crate::lines::*;
     pub use crate::point::*;
     pub use crate::point3::*;
+    pub use crate::polygon::*;
     pub use crate::rect::*;
     pub use crate::rectf::*;
 }
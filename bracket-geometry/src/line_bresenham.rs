@@ -4,7 +4,10 @@
 use crate::prelude::Point;
 use core::iter::Iterator;
 
-/// Line-drawing iterator
+/// Line-drawing iterator. Yields each point on the line from `start` up to
+/// (but not including) `end`, without allocating. The struct can be stored
+/// and stepped manually; use [`BresenhamInclusive`] if you need the end
+/// point as well.
 pub struct Bresenham {
     x: i32,
     y: i32,
@@ -1,4 +1,4 @@
-use crate::prelude::{Bresenham, DistanceAlg, Point};
+use crate::prelude::{Bresenham, DistanceAlg, Point, PointF};
 
 /// Enumeration of available 2D Distance algorithms
 pub enum LineAlg {
@@ -59,9 +59,64 @@ pub fn line2d_vector(start: Point, end: Point) -> Vec<Point> {
     result
 }
 
+/// Plots a line `width` cells thick between two points, returning every cell
+/// within `width/2` of the ideal line with no duplicates. A width of 1 (or
+/// less) is exactly a plain Bresenham line. Line ends are cut square rather
+/// than rounded.
+pub fn line_thick(start: Point, end: Point, width: i32) -> Vec<Point> {
+    if width <= 1 {
+        return line2d_bresenham(start, end);
+    }
+    let half = width as f32 / 2.0;
+    let a = PointF::new(start.x as f32, start.y as f32);
+    let b = PointF::new(end.x as f32, end.y as f32);
+    let dir = b - a;
+    let len = dir.dot(dir).sqrt();
+    let reach = half.ceil() as i32;
+    let mut result = Vec::new();
+    for y in (start.y.min(end.y) - reach)..=(start.y.max(end.y) + reach) {
+        for x in (start.x.min(end.x) - reach)..=(start.x.max(end.x) + reach) {
+            let p = PointF::new(x as f32, y as f32);
+            if len <= f32::EPSILON {
+                // Degenerate segment: stamp a disc around the single point.
+                let d = p - a;
+                if d.dot(d).sqrt() + 0.5 <= half {
+                    result.push(Point::new(x, y));
+                }
+                continue;
+            }
+            let closest = closest_point_on_segment(p, a, b);
+            let d = p - closest;
+            let dist = d.dot(d).sqrt();
+            // Signed perpendicular distance to the infinite line; the sign
+            // places the extra row/column of even widths on one side. The
+            // parallel component is the overshoot past the segment's ends.
+            let perp = ((dir.x * (p.y - a.y)) - (dir.y * (p.x - a.x))) / len;
+            let parallel = ((dist * dist) - (perp * perp)).max(0.0).sqrt();
+            if parallel <= 0.5 && perp + 0.5 > -half && perp + 0.5 <= half {
+                result.push(Point::new(x, y));
+            }
+        }
+    }
+    result
+}
+
+/// Returns the point on the segment `a`-`b` closest to `p`. The projection
+/// parameter is clamped to `[0, 1]`, so points projecting beyond the segment
+/// return the nearest endpoint. A degenerate segment (`a == b`) returns `a`.
+pub fn closest_point_on_segment(p: PointF, a: PointF, b: PointF) -> PointF {
+    let ab = b - a;
+    let length_squared = ab.dot(ab);
+    if length_squared <= f32::EPSILON {
+        return a;
+    }
+    let t = ((p - a).dot(ab) / length_squared).clamp(0.0, 1.0);
+    a + (ab * t)
+}
+
 #[cfg(test)]
 mod tests {
-    use crate::prelude::{line2d_bresenham, line2d_vector, Point};
+    use crate::prelude::{closest_point_on_segment, line2d_bresenham, line2d_vector, Point, PointF};
 
     #[test]
     fn vector_line_h() {
@@ -171,6 +226,73 @@ mod tests {
         );
     }
 
+    #[test]
+    fn thick_line_width_one_is_bresenham() {
+        use crate::prelude::line_thick;
+
+        let pt = Point::new(2, 3);
+        let pt2 = Point::new(9, -1);
+        assert_eq!(line_thick(pt, pt2, 1), line2d_bresenham(pt, pt2));
+    }
+
+    #[test]
+    fn thick_line_horizontal_widths() {
+        use crate::prelude::line_thick;
+        use std::collections::HashSet;
+
+        // Width 3 around a horizontal line covers rows -1..=1.
+        let line = line_thick(Point::new(0, 0), Point::new(5, 0), 3);
+        let unique: HashSet<Point> = line.iter().copied().collect();
+        assert_eq!(line.len(), unique.len(), "duplicate points");
+        assert_eq!(line.len(), 18);
+        assert!(line.iter().all(|p| (0..=5).contains(&p.x) && p.y.abs() <= 1));
+
+        // Width 2 covers exactly two rows.
+        let line = line_thick(Point::new(0, 0), Point::new(5, 0), 2);
+        assert_eq!(line.len(), 12);
+        let rows: HashSet<i32> = line.iter().map(|p| p.y).collect();
+        assert_eq!(rows.len(), 2);
+    }
+
+    #[test]
+    fn thick_line_diagonal_no_duplicates() {
+        use crate::prelude::line_thick;
+        use std::collections::HashSet;
+
+        let line = line_thick(Point::new(-3, 2), Point::new(7, -5), 4);
+        let unique: HashSet<Point> = line.iter().copied().collect();
+        assert_eq!(line.len(), unique.len());
+        assert!(!line.is_empty());
+    }
+
+    #[test]
+    fn closest_point_inside_segment() {
+        let p = closest_point_on_segment(
+            PointF::new(5.0, 5.0),
+            PointF::new(0.0, 0.0),
+            PointF::new(10.0, 0.0),
+        );
+        assert!(f32::abs(p.x - 5.0) < std::f32::EPSILON);
+        assert!(f32::abs(p.y) < std::f32::EPSILON);
+    }
+
+    #[test]
+    fn closest_point_clamps_to_endpoints() {
+        let a = PointF::new(0.0, 0.0);
+        let b = PointF::new(10.0, 0.0);
+        let before = closest_point_on_segment(PointF::new(-5.0, 3.0), a, b);
+        assert_eq!(before, a);
+        let after = closest_point_on_segment(PointF::new(15.0, -3.0), a, b);
+        assert_eq!(after, b);
+    }
+
+    #[test]
+    fn closest_point_degenerate_segment() {
+        let a = PointF::new(2.0, 2.0);
+        let p = closest_point_on_segment(PointF::new(5.0, 5.0), a, a);
+        assert_eq!(p, a);
+    }
+
     #[test]
     pub fn infinite_loop_bug181() {
         let pt = Point { x: 2, y: 2 };
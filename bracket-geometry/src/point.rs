@@ -111,6 +111,53 @@ impl Point {
         Self::new(v.x as i32, v.y as i32)
     }
 
+    /// Snaps the point to a coarser grid, flooring each coordinate to the
+    /// nearest lower multiple of `grid`. Uses Euclidean division, so
+    /// negative coordinates snap downwards correctly (e.g. -1 snaps to -8
+    /// on an 8-cell grid, not 0). A `grid` of zero or less returns the
+    /// point unchanged.
+    #[inline]
+    #[must_use]
+    pub fn snap_to_grid(self, grid: i32) -> Point {
+        if grid <= 0 {
+            return self;
+        }
+        Point {
+            x: self.x.div_euclid(grid) * grid,
+            y: self.y.div_euclid(grid) * grid,
+        }
+    }
+
+    /// Reflects the point across the vertical line `x = axis_x`, computing
+    /// `2 * axis_x - x`. Useful for generating symmetric level geometry.
+    #[inline]
+    #[must_use]
+    pub fn reflect_x(self, axis_x: i32) -> Point {
+        Point {
+            x: (2 * axis_x) - self.x,
+            y: self.y,
+        }
+    }
+
+    /// Reflects the point across the horizontal line `y = axis_y`, computing
+    /// `2 * axis_y - y`.
+    #[inline]
+    #[must_use]
+    pub fn reflect_y(self, axis_y: i32) -> Point {
+        Point {
+            x: self.x,
+            y: (2 * axis_y) - self.y,
+        }
+    }
+
+    /// Converts the point to a `PointF`. Equivalent to `to_vec2`, but named
+    /// for the `PointF` alias so sub-tile math reads naturally.
+    #[inline]
+    #[must_use]
+    pub fn as_pointf(self) -> PointF {
+        PointF::new(self.x as f32, self.y as f32)
+    }
+
     /*
     /// Creates a point from an `UltraViolet` vec2i
     pub fn from_vec2i(v: Vec2i) -> Self {
@@ -296,11 +343,68 @@ impl ops::DivAssign for Point {
     }
 }
 
+///////////////////////////////////////////////////////////////////////////////////////
+/// Mixed Point/PointF helpers. `PointF` is a re-exported ultraviolet `Vec2`,
+/// so we can't implement the `ops` traits for it here; these free functions
+/// make sub-tile positions plus integer tile offsets ergonomic instead.
+
+/// Adds an integer `Point` offset to a `PointF` position.
+#[inline]
+#[must_use]
+pub fn point_f_add_point(a: PointF, b: Point) -> PointF {
+    a + b.as_pointf()
+}
+
+/// Subtracts an integer `Point` offset from a `PointF` position.
+#[inline]
+#[must_use]
+pub fn point_f_sub_point(a: PointF, b: Point) -> PointF {
+    a - b.as_pointf()
+}
+
 // Unit tests
 #[cfg(test)]
 mod tests {
     use super::Point;
 
+    #[test]
+    fn reflect_point() {
+        assert_eq!(Point::new(3, 7).reflect_x(5), Point::new(7, 7));
+        assert_eq!(Point::new(3, 7).reflect_y(5), Point::new(3, 3));
+        // A point on the axis is its own reflection.
+        assert_eq!(Point::new(5, 7).reflect_x(5), Point::new(5, 7));
+        // Reflecting twice returns the original point.
+        assert_eq!(Point::new(-2, 9).reflect_x(4).reflect_x(4), Point::new(-2, 9));
+    }
+
+    #[test]
+    fn snap_to_grid() {
+        assert_eq!(Point::new(13, 21).snap_to_grid(8), Point::new(8, 16));
+        assert_eq!(Point::new(16, 0).snap_to_grid(8), Point::new(16, 0));
+        // Negative coordinates snap downwards, not towards zero.
+        assert_eq!(Point::new(-1, -8).snap_to_grid(8), Point::new(-8, -8));
+        assert_eq!(Point::new(-9, 7).snap_to_grid(8), Point::new(-16, 0));
+        // A non-positive grid is a no-op.
+        assert_eq!(Point::new(13, 21).snap_to_grid(0), Point::new(13, 21));
+    }
+
+    #[test]
+    fn pointf_point_helpers() {
+        use super::{point_f_add_point, point_f_sub_point, PointF};
+
+        let sub_tile = PointF::new(1.25, 2.5);
+        let offset = Point::new(3, -1);
+        assert_eq!(offset.as_pointf(), PointF::new(3.0, -1.0));
+        assert_eq!(
+            point_f_add_point(sub_tile, offset),
+            PointF::new(4.25, 1.5)
+        );
+        assert_eq!(
+            point_f_sub_point(sub_tile, offset),
+            PointF::new(-1.75, 3.5)
+        );
+    }
+
     #[test]
     fn new_point() {
         let pt = Point::new(1, 2);
@@ -0,0 +1,164 @@
+use crate::prelude::{Point, PointF};
+
+/// Computes the convex hull of a point set using Andrew's monotone chain,
+/// returning the hull vertices in counter-clockwise order (with the y axis
+/// pointing up; on a y-down screen grid the winding appears clockwise).
+/// Collinear points along hull edges are omitted. Fewer than three distinct
+/// input points return the input, deduplicated and sorted.
+#[must_use]
+pub fn convex_hull(points: &[Point]) -> Vec<Point> {
+    let mut pts: Vec<Point> = points.to_vec();
+    pts.sort_by(|a, b| a.x.cmp(&b.x).then(a.y.cmp(&b.y)));
+    pts.dedup();
+    if pts.len() < 3 {
+        return pts;
+    }
+
+    // Cross product of (o->a) x (o->b); positive for a counter-clockwise
+    // turn. i64 math avoids overflow for points near the i32 extremes.
+    fn cross(o: Point, a: Point, b: Point) -> i64 {
+        (i64::from(a.x) - i64::from(o.x)) * (i64::from(b.y) - i64::from(o.y))
+            - (i64::from(a.y) - i64::from(o.y)) * (i64::from(b.x) - i64::from(o.x))
+    }
+
+    let mut lower: Vec<Point> = Vec::new();
+    for &p in &pts {
+        while lower.len() >= 2 && cross(lower[lower.len() - 2], lower[lower.len() - 1], p) <= 0 {
+            lower.pop();
+        }
+        lower.push(p);
+    }
+    let mut upper: Vec<Point> = Vec::new();
+    for &p in pts.iter().rev() {
+        while upper.len() >= 2 && cross(upper[upper.len() - 2], upper[upper.len() - 1], p) <= 0 {
+            upper.pop();
+        }
+        upper.push(p);
+    }
+
+    // The last point of each chain is the first point of the other.
+    lower.pop();
+    upper.pop();
+    lower.extend(upper);
+    lower
+}
+
+/// Tests whether `p` lies inside the polygon described by `vertices`, using
+/// the even-odd ray-casting rule. The polygon is implicitly closed (the last
+/// vertex connects back to the first) and may be concave. Points exactly on
+/// an edge follow the ray-cast convention: edges on the "lower" side of the
+/// point count as inside, giving consistent results for adjacent polygons
+/// sharing an edge. Fewer than three vertices always returns `false`.
+pub fn point_in_polygon(p: PointF, vertices: &[PointF]) -> bool {
+    if vertices.len() < 3 {
+        return false;
+    }
+
+    let mut inside = false;
+    let mut j = vertices.len() - 1;
+    for i in 0..vertices.len() {
+        let a = vertices[i];
+        let b = vertices[j];
+        if ((a.y > p.y) != (b.y > p.y))
+            && (p.x < (b.x - a.x) * (p.y - a.y) / (b.y - a.y) + a.x)
+        {
+            inside = !inside;
+        }
+        j = i;
+    }
+    inside
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::{point_in_polygon, PointF};
+
+    fn unit_square() -> Vec<PointF> {
+        vec![
+            PointF::new(0.0, 0.0),
+            PointF::new(10.0, 0.0),
+            PointF::new(10.0, 10.0),
+            PointF::new(0.0, 10.0),
+        ]
+    }
+
+    #[test]
+    fn point_inside_square() {
+        assert!(point_in_polygon(PointF::new(5.0, 5.0), &unit_square()));
+    }
+
+    #[test]
+    fn point_outside_square() {
+        assert!(!point_in_polygon(PointF::new(15.0, 5.0), &unit_square()));
+        assert!(!point_in_polygon(PointF::new(5.0, -1.0), &unit_square()));
+    }
+
+    #[test]
+    fn concave_polygon() {
+        // A "U" shape: the notch in the middle is outside.
+        let poly = vec![
+            PointF::new(0.0, 0.0),
+            PointF::new(10.0, 0.0),
+            PointF::new(10.0, 10.0),
+            PointF::new(7.0, 10.0),
+            PointF::new(7.0, 3.0),
+            PointF::new(3.0, 3.0),
+            PointF::new(3.0, 10.0),
+            PointF::new(0.0, 10.0),
+        ];
+        assert!(point_in_polygon(PointF::new(1.5, 5.0), &poly));
+        assert!(point_in_polygon(PointF::new(8.5, 5.0), &poly));
+        assert!(!point_in_polygon(PointF::new(5.0, 5.0), &poly));
+    }
+
+    #[test]
+    fn degenerate_polygon() {
+        assert!(!point_in_polygon(PointF::new(0.0, 0.0), &[]));
+        assert!(!point_in_polygon(
+            PointF::new(0.0, 0.0),
+            &[PointF::new(0.0, 0.0), PointF::new(1.0, 1.0)]
+        ));
+    }
+
+    #[test]
+    fn hull_of_square_with_interior() {
+        use crate::prelude::{convex_hull, Point};
+
+        let points = vec![
+            Point::new(0, 0),
+            Point::new(10, 0),
+            Point::new(10, 10),
+            Point::new(0, 10),
+            // Interior and edge-collinear points are dropped.
+            Point::new(5, 5),
+            Point::new(5, 0),
+            Point::new(0, 5),
+        ];
+        let hull = convex_hull(&points);
+        assert_eq!(hull.len(), 4);
+        assert_eq!(
+            hull,
+            vec![
+                Point::new(0, 0),
+                Point::new(10, 0),
+                Point::new(10, 10),
+                Point::new(0, 10),
+            ]
+        );
+    }
+
+    #[test]
+    fn hull_small_inputs() {
+        use crate::prelude::{convex_hull, Point};
+
+        assert!(convex_hull(&[]).is_empty());
+        let single = vec![Point::new(3, 4)];
+        assert_eq!(convex_hull(&single), single);
+        // Duplicates collapse.
+        let dupes = vec![Point::new(1, 1), Point::new(2, 2), Point::new(1, 1)];
+        assert_eq!(
+            convex_hull(&dupes),
+            vec![Point::new(1, 1), Point::new(2, 2)]
+        );
+    }
+}
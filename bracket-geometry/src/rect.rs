@@ -67,6 +67,28 @@ impl Rect {
         }
     }
 
+    /// Returns the smallest rectangle containing every point in `points`, or
+    /// `None` for an empty slice. The result follows the rectangle's half-open
+    /// convention: every input point satisfies `point_in_rect`, so `x2`/`y2`
+    /// are one past the largest coordinates.
+    #[must_use]
+    pub fn bounding_box(points: &[Point]) -> Option<Rect> {
+        let first = points.first()?;
+        let mut result = Rect {
+            x1: first.x,
+            y1: first.y,
+            x2: first.x + 1,
+            y2: first.y + 1,
+        };
+        for p in &points[1..] {
+            result.x1 = i32::min(result.x1, p.x);
+            result.y1 = i32::min(result.y1, p.y);
+            result.x2 = i32::max(result.x2, p.x + 1);
+            result.y2 = i32::max(result.y2, p.y + 1);
+        }
+        Some(result)
+    }
+
     /// Returns true if this overlaps with other
     #[must_use]
     pub fn intersect(&self, other: &Rect) -> bool {
@@ -109,6 +131,238 @@ impl Rect {
         result
     }
 
+    /// Returns the integer cells on the outline of the ellipse inscribed in
+    /// the rectangle, using a Bresenham-style rasterization. Cells are
+    /// de-duplicated but returned in no particular order. A square rectangle
+    /// yields a circle.
+    pub fn ellipse_cells(&self) -> impl Iterator<Item = Point> {
+        // Adapted from the "plot ellipse inside rectangle" algorithm at
+        // http://members.chello.at/~easyfilter/bresenham.html, operating on
+        // the rect's cell bounds (x1..x2 half-open, so the last cell column
+        // is x2-1).
+        let (mut x0, mut y0) = (i64::from(self.x1.min(self.x2)), i64::from(self.y1.min(self.y2)));
+        let (mut x1, mut y1) = (
+            i64::from(self.x1.max(self.x2)) - 1,
+            i64::from(self.y1.max(self.y2)) - 1,
+        );
+        let mut cells = HashSet::new();
+        if x1 < x0 || y1 < y0 {
+            return cells.into_iter();
+        }
+
+        let a = x1 - x0;
+        let b = y1 - y0;
+        let mut b1 = b & 1;
+        let mut dx = 4 * (1 - a) * b * b;
+        let mut dy = 4 * (b1 + 1) * a * a;
+        let mut err = dx + dy + b1 * a * a;
+
+        y0 += (b + 1) / 2;
+        y1 = y0 - b1;
+        let a8 = 8 * a * a;
+        b1 = 8 * b * b;
+
+        loop {
+            cells.insert(Point::new(x1 as i32, y0 as i32));
+            cells.insert(Point::new(x0 as i32, y0 as i32));
+            cells.insert(Point::new(x0 as i32, y1 as i32));
+            cells.insert(Point::new(x1 as i32, y1 as i32));
+            let e2 = 2 * err;
+            if e2 <= dy {
+                y0 += 1;
+                y1 -= 1;
+                dy += a8;
+                err += dy;
+            }
+            if e2 >= dx || 2 * err > dy {
+                x0 += 1;
+                x1 -= 1;
+                dx += b1;
+                err += dx;
+            }
+            if x0 > x1 {
+                break;
+            }
+        }
+
+        // Finish the tips of a tall, narrow ellipse.
+        while y0 - y1 < b {
+            cells.insert(Point::new((x0 - 1) as i32, y0 as i32));
+            cells.insert(Point::new((x1 + 1) as i32, y0 as i32));
+            y0 += 1;
+            cells.insert(Point::new((x0 - 1) as i32, y1 as i32));
+            cells.insert(Point::new((x1 + 1) as i32, y1 as i32));
+            y1 -= 1;
+        }
+
+        cells.into_iter()
+    }
+
+    /// Returns a uniformly chosen interior cell. A 1x1 rectangle always
+    /// returns its single cell; a degenerate (zero-sized) rectangle returns
+    /// its origin corner. Requires the `bracket-random` feature.
+    #[cfg(feature = "bracket-random")]
+    #[must_use]
+    pub fn random_point(&self, rng: &mut bracket_random::prelude::RandomNumberGenerator) -> Point {
+        let (x1, x2) = (self.x1.min(self.x2), self.x1.max(self.x2));
+        let (y1, y2) = (self.y1.min(self.y2), self.y1.max(self.y2));
+        Point::new(
+            if x2 > x1 { rng.range(x1, x2) } else { x1 },
+            if y2 > y1 { rng.range(y1, y2) } else { y1 },
+        )
+    }
+
+    /// Returns the cell on the rectangle's boundary closest to `p` - handy
+    /// for snapping leader lines to a box edge. If `p` is inside the
+    /// rectangle, the nearest of the four edges is returned rather than `p`
+    /// itself. A degenerate (zero-sized) rectangle returns its origin corner.
+    #[must_use]
+    pub fn nearest_edge_point(&self, p: Point) -> Point {
+        let (x1, x2) = (self.x1.min(self.x2), self.x1.max(self.x2));
+        let (y1, y2) = (self.y1.min(self.y2), self.y1.max(self.y2));
+        if x2 <= x1 || y2 <= y1 {
+            return Point::new(x1, y1);
+        }
+        // Last valid cell in each dimension (the bounds are half-open).
+        let xmax = x2 - 1;
+        let ymax = y2 - 1;
+        if !self.point_in_rect(p) {
+            return Point::new(p.x.max(x1).min(xmax), p.y.max(y1).min(ymax));
+        }
+        let d_left = p.x - x1;
+        let d_right = xmax - p.x;
+        let d_top = p.y - y1;
+        let d_bottom = ymax - p.y;
+        let nearest = d_left.min(d_right).min(d_top).min(d_bottom);
+        if nearest == d_left {
+            Point::new(x1, p.y)
+        } else if nearest == d_right {
+            Point::new(xmax, p.y)
+        } else if nearest == d_top {
+            Point::new(p.x, y1)
+        } else {
+            Point::new(p.x, ymax)
+        }
+    }
+
+    /// Returns an iterator over the perimeter cells of the rectangle, with
+    /// no duplicates at the corners. For rectangles narrower than 3 in
+    /// either dimension every cell is a border cell. Useful for drawing
+    /// custom borders.
+    pub fn border_cells(&self) -> impl Iterator<Item = Point> {
+        let (x1, x2) = (self.x1.min(self.x2), self.x1.max(self.x2));
+        let (y1, y2) = (self.y1.min(self.y2), self.y1.max(self.y2));
+        let mut cells = Vec::new();
+        if x2 > x1 && y2 > y1 {
+            for x in x1..x2 {
+                cells.push(Point::new(x, y1));
+                if y2 - y1 > 1 {
+                    cells.push(Point::new(x, y2 - 1));
+                }
+            }
+            for y in (y1 + 1)..(y2 - 1) {
+                cells.push(Point::new(x1, y));
+                if x2 - x1 > 1 {
+                    cells.push(Point::new(x2 - 1, y));
+                }
+            }
+        }
+        cells.into_iter()
+    }
+
+    /// Scales the rectangle to the largest size that fits inside
+    /// `container` while preserving its aspect ratio, centered within the
+    /// container (letterboxing). Degenerate rectangles produce a zero-sized
+    /// result at the container's center.
+    #[must_use]
+    pub fn fit_inside(&self, container: &Rect) -> Rect {
+        self.scale_to(container, false)
+    }
+
+    /// Scales the rectangle to the smallest size that completely covers
+    /// `container` while preserving its aspect ratio, centered on the
+    /// container (cropping any overflow).
+    #[must_use]
+    pub fn fill_inside(&self, container: &Rect) -> Rect {
+        self.scale_to(container, true)
+    }
+
+    fn scale_to(&self, container: &Rect, cover: bool) -> Rect {
+        let (w, h) = (self.width() as f32, self.height() as f32);
+        let (cw, ch) = (container.width() as f32, container.height() as f32);
+        if w <= 0.0 || h <= 0.0 {
+            let center = container.center();
+            return Rect::with_exact(center.x, center.y, center.x, center.y);
+        }
+        let scale = if cover {
+            f32::max(cw / w, ch / h)
+        } else {
+            f32::min(cw / w, ch / h)
+        };
+        // Rounding can miss the container edge by a cell; clamp the limiting
+        // dimension back onto it.
+        let mut new_w = (w * scale).round() as i32;
+        let mut new_h = (h * scale).round() as i32;
+        if cover {
+            new_w = new_w.max(container.width());
+            new_h = new_h.max(container.height());
+        } else {
+            new_w = new_w.min(container.width());
+            new_h = new_h.min(container.height());
+        }
+        let x1 = container.x1 + ((container.width() - new_w) / 2);
+        let y1 = container.y1 + ((container.height() - new_h) / 2);
+        Rect::with_size(x1, y1, new_w, new_h)
+    }
+
+    /// Splits the rectangle into a `cols` x `rows` grid of sub-rectangles
+    /// covering the original exactly: integer rounding leaves no gaps, with
+    /// the last row/column absorbing any remainder. Results are in row-major
+    /// order. Returns an empty vector if either count is less than one.
+    #[must_use]
+    pub fn split_into(&self, cols: i32, rows: i32) -> Vec<Rect> {
+        if cols < 1 || rows < 1 {
+            return Vec::new();
+        }
+        let cell_w = self.width() / cols;
+        let cell_h = self.height() / rows;
+        let mut result = Vec::with_capacity((cols * rows) as usize);
+        for row in 0..rows {
+            for col in 0..cols {
+                let x1 = self.x1 + (col * cell_w);
+                let y1 = self.y1 + (row * cell_h);
+                let x2 = if col == cols - 1 { self.x2 } else { x1 + cell_w };
+                let y2 = if row == rows - 1 { self.y2 } else { y1 + cell_h };
+                result.push(Rect::with_exact(x1, y1, x2, y2));
+            }
+        }
+        result
+    }
+
+    /// Splits the rectangle along the horizontal line `y = at` (in absolute
+    /// coordinates, clamped to the rectangle), returning the (top, bottom)
+    /// halves. Together they cover the original exactly.
+    #[must_use]
+    pub fn split_horizontal(&self, at: i32) -> (Rect, Rect) {
+        let at = at.max(self.y1).min(self.y2);
+        (
+            Rect::with_exact(self.x1, self.y1, self.x2, at),
+            Rect::with_exact(self.x1, at, self.x2, self.y2),
+        )
+    }
+
+    /// Splits the rectangle along the vertical line `x = at` (in absolute
+    /// coordinates, clamped to the rectangle), returning the (left, right)
+    /// halves. Together they cover the original exactly.
+    #[must_use]
+    pub fn split_vertical(&self, at: i32) -> (Rect, Rect) {
+        let at = at.max(self.x1).min(self.x2);
+        (
+            Rect::with_exact(self.x1, self.y1, at, self.y2),
+            Rect::with_exact(at, self.y1, self.x2, self.y2),
+        )
+    }
+
     /// Returns the rectangle's width
     #[must_use]
     pub fn width(&self) -> i32 {
@@ -186,6 +440,160 @@ mod tests {
         assert!(!points.contains(&Point::new(1, 1)));
     }
 
+    #[test]
+    fn test_ellipse_cells_square_is_circle() {
+        use crate::prelude::BresenhamCircle;
+        use std::collections::HashSet;
+
+        // A 7x7 square inscribes a radius-3 circle around its center.
+        let rect = Rect::with_size(0, 0, 7, 7);
+        let ellipse: HashSet<Point> = rect.ellipse_cells().collect();
+        let circle: HashSet<Point> = BresenhamCircle::new(Point::new(3, 3), 3).collect();
+        assert_eq!(ellipse, circle);
+    }
+
+    #[test]
+    fn test_ellipse_cells_stay_in_rect() {
+        let rect = Rect::with_size(2, 3, 11, 5);
+        let cells: Vec<Point> = rect.ellipse_cells().collect();
+        assert!(!cells.is_empty());
+        for cell in cells {
+            assert!(rect.point_in_rect(cell));
+        }
+    }
+
+    #[test]
+    fn test_bounding_box() {
+        assert!(Rect::bounding_box(&[]).is_none());
+
+        let points = vec![Point::new(3, 7), Point::new(-2, 4), Point::new(5, -1)];
+        let bbox = Rect::bounding_box(&points).unwrap();
+        assert_eq!(bbox, Rect::with_exact(-2, -1, 6, 8));
+        for p in &points {
+            assert!(bbox.point_in_rect(*p));
+        }
+    }
+
+    #[cfg(feature = "bracket-random")]
+    #[test]
+    fn test_random_point() {
+        use bracket_random::prelude::RandomNumberGenerator;
+
+        let mut rng = RandomNumberGenerator::new();
+        let rect = Rect::with_size(3, 5, 7, 4);
+        for _ in 0..100 {
+            assert!(rect.point_in_rect(rect.random_point(&mut rng)));
+        }
+        let single = Rect::with_size(2, 2, 1, 1);
+        assert_eq!(single.random_point(&mut rng), Point::new(2, 2));
+    }
+
+    #[test]
+    fn test_fit_inside() {
+        // A 2:1 image letterboxed into a 20x20 panel spans the full width.
+        let image = Rect::with_size(0, 0, 10, 5);
+        let panel = Rect::with_size(0, 0, 20, 20);
+        let fitted = image.fit_inside(&panel);
+        assert_eq!(fitted, Rect::with_size(0, 5, 20, 10));
+        // An already-matching aspect fills the container exactly.
+        let square = Rect::with_size(3, 3, 5, 5);
+        assert_eq!(square.fit_inside(&panel), panel);
+    }
+
+    #[test]
+    fn test_fill_inside() {
+        let image = Rect::with_size(0, 0, 10, 5);
+        let panel = Rect::with_size(0, 0, 20, 20);
+        let filled = image.fill_inside(&panel);
+        // Covers the panel, overflowing horizontally, centered.
+        assert_eq!(filled, Rect::with_size(-10, 0, 40, 20));
+        assert!(filled.x1 <= panel.x1 && filled.x2 >= panel.x2);
+        assert!(filled.y1 <= panel.y1 && filled.y2 >= panel.y2);
+    }
+
+    #[test]
+    fn test_nearest_edge_point() {
+        let rect = Rect::with_size(0, 0, 10, 10);
+        // Outside: clamps to the closest boundary cell.
+        assert_eq!(rect.nearest_edge_point(Point::new(-5, 4)), Point::new(0, 4));
+        assert_eq!(rect.nearest_edge_point(Point::new(20, 20)), Point::new(9, 9));
+        assert_eq!(rect.nearest_edge_point(Point::new(4, -1)), Point::new(4, 0));
+        // Inside: snaps to the nearest of the four edges.
+        assert_eq!(rect.nearest_edge_point(Point::new(1, 5)), Point::new(0, 5));
+        assert_eq!(rect.nearest_edge_point(Point::new(5, 8)), Point::new(5, 9));
+        let edge = rect.nearest_edge_point(Point::new(5, 5));
+        assert!(edge != Point::new(5, 5));
+        assert!(rect.border_cells().any(|c| c == edge));
+    }
+
+    #[test]
+    fn test_border_cells() {
+        use std::collections::HashSet;
+
+        let rect = Rect::with_size(1, 1, 5, 4);
+        let border: Vec<Point> = rect.border_cells().collect();
+        let unique: HashSet<Point> = border.iter().copied().collect();
+        assert_eq!(border.len(), unique.len(), "corner cells duplicated");
+        // Perimeter of a 5x4 rect: 2*5 + 2*4 - 4 corners counted once.
+        assert_eq!(border.len(), 14);
+        for cell in &border {
+            assert!(rect.point_in_rect(*cell));
+            let interior = cell.x > rect.x1
+                && cell.x < rect.x2 - 1
+                && cell.y > rect.y1
+                && cell.y < rect.y2 - 1;
+            assert!(!interior);
+        }
+    }
+
+    #[test]
+    fn test_border_cells_thin() {
+        use std::collections::HashSet;
+
+        // Rects thinner than 3 cells are all border.
+        for (w, h) in [(1, 1), (2, 5), (5, 1)] {
+            let rect = Rect::with_size(0, 0, w, h);
+            let border: HashSet<Point> = rect.border_cells().collect();
+            assert_eq!(border, rect.point_set());
+            assert_eq!(border.len(), (w * h) as usize);
+        }
+        assert_eq!(Rect::zero().border_cells().count(), 0);
+    }
+
+    #[test]
+    fn test_split_into_covers_exactly() {
+        use std::collections::HashSet;
+
+        // 10x7 doesn't divide evenly by 3x2; the last row/column absorb the
+        // remainder and the pieces still tile the original exactly.
+        let rect = Rect::with_size(2, 3, 10, 7);
+        let pieces = rect.split_into(3, 2);
+        assert_eq!(pieces.len(), 6);
+        let mut covered: HashSet<Point> = HashSet::new();
+        for piece in &pieces {
+            piece.for_each(|p| {
+                assert!(covered.insert(p), "pieces overlap at {:?}", p);
+            });
+        }
+        assert_eq!(covered, rect.point_set());
+        assert!(rect.split_into(0, 2).is_empty());
+    }
+
+    #[test]
+    fn test_split_halves() {
+        let rect = Rect::with_size(0, 0, 10, 10);
+        let (top, bottom) = rect.split_horizontal(4);
+        assert_eq!(top, Rect::with_exact(0, 0, 10, 4));
+        assert_eq!(bottom, Rect::with_exact(0, 4, 10, 10));
+        let (left, right) = rect.split_vertical(7);
+        assert_eq!(left, Rect::with_exact(0, 0, 7, 10));
+        assert_eq!(right, Rect::with_exact(7, 0, 10, 10));
+        // Out-of-range split points clamp, leaving one empty half.
+        let (top, bottom) = rect.split_horizontal(100);
+        assert_eq!(top, rect);
+        assert_eq!(bottom.height(), 0);
+    }
+
     #[test]
     fn test_rect_callback() {
         use std::collections::HashSet;
@@ -12,9 +12,13 @@ mod js_seed;
 
 mod iterators;
 
+mod weighted;
+
 pub mod prelude {
     pub use crate::random::*;
 
+    pub use crate::weighted::*;
+
     #[cfg(feature = "parsing")]
     pub use crate::parsing::*;
 
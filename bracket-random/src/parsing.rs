@@ -38,12 +38,29 @@ impl Default for DiceType {
     }
 }
 
-#[derive(Debug, Clone)]
-pub struct DiceParseError;
+/// Why a dice string failed to parse, so UIs can tell the user what was
+/// wrong with their expression rather than just rejecting it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DiceParseError {
+    /// The input was empty (or all whitespace).
+    Empty,
+    /// No `NdM` dice expression could be found in the input.
+    InvalidToken,
+    /// A numeric component was too large to fit in an `i32`.
+    Overflow,
+    /// The expression used a modifier the parser doesn't support, such as
+    /// `*`, `/` or exploding-dice notation.
+    UnsupportedModifier,
+}
 
 impl std::fmt::Display for DiceParseError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "Invalid dice string")
+        match self {
+            DiceParseError::Empty => write!(f, "Empty dice string"),
+            DiceParseError::InvalidToken => write!(f, "Invalid dice string"),
+            DiceParseError::Overflow => write!(f, "Dice value out of range"),
+            DiceParseError::UnsupportedModifier => write!(f, "Unsupported dice modifier"),
+        }
     }
 }
 
@@ -59,6 +76,9 @@ impl error::Error for DiceParseError {
 #[cfg(feature = "parsing")]
 pub fn parse_dice_string(dice: &str) -> Result<DiceType, DiceParseError> {
     let dice = &dice.split_whitespace().collect::<Vec<_>>().join("");
+    if dice.is_empty() {
+        return Err(DiceParseError::Empty);
+    }
     lazy_static! {
         static ref DICE_RE: Regex = Regex::new(r"(\d+)d(\d+)([\+\-]\d+)?").unwrap();
     }
@@ -68,29 +88,39 @@ pub fn parse_dice_string(dice: &str) -> Result<DiceType, DiceParseError> {
         did_something = true;
         if let Some(group) = cap.get(1) {
             match group.as_str().parse::<i32>() {
+                // The regex only matches digits, so a parse failure is an
+                // overflow.
                 Ok(number) => result.n_dice = number,
-                Err(_) => return Err(DiceParseError {}),
+                Err(_) => return Err(DiceParseError::Overflow),
             }
         } else {
-            return Err(DiceParseError {});
+            return Err(DiceParseError::InvalidToken);
         }
         if let Some(group) = cap.get(2) {
             match group.as_str().parse::<i32>() {
                 Ok(number) => result.die_type = number,
-                Err(_) => return Err(DiceParseError {}),
+                Err(_) => return Err(DiceParseError::Overflow),
             }
         } else {
-            return Err(DiceParseError {});
+            return Err(DiceParseError::InvalidToken);
         }
         if let Some(group) = cap.get(3) {
             match group.as_str().parse::<i32>() {
                 Ok(number) => result.bonus = number,
-                Err(_) => return Err(DiceParseError {}),
+                Err(_) => return Err(DiceParseError::Overflow),
             }
         }
     }
     if !did_something {
-        return Err(DiceParseError {});
+        return Err(DiceParseError::InvalidToken);
+    }
+    // Flag arithmetic the parser doesn't understand, e.g. "3d6*2" or
+    // exploding-dice notation, rather than silently ignoring it.
+    if let Some(found) = DICE_RE.find(dice) {
+        let remainder = [&dice[..found.start()], &dice[found.end()..]].concat();
+        if remainder.contains(['*', '/', '%', '^', '!']) {
+            return Err(DiceParseError::UnsupportedModifier);
+        }
     }
     Ok(result)
 }
@@ -138,6 +168,27 @@ mod tests {
         assert!(parse_dice_string("blah").is_err());
     }
 
+    #[test]
+    fn parse_error_reasons() {
+        use super::DiceParseError;
+
+        assert_eq!(parse_dice_string(""), Err(DiceParseError::Empty));
+        assert_eq!(parse_dice_string("   "), Err(DiceParseError::Empty));
+        assert_eq!(parse_dice_string("blah"), Err(DiceParseError::InvalidToken));
+        assert_eq!(
+            parse_dice_string("99999999999d6"),
+            Err(DiceParseError::Overflow)
+        );
+        assert_eq!(
+            parse_dice_string("3d6*2"),
+            Err(DiceParseError::UnsupportedModifier)
+        );
+        assert_eq!(
+            parse_dice_string("1d6!"),
+            Err(DiceParseError::UnsupportedModifier)
+        );
+    }
+
     #[cfg(feature = "serde")]
     #[test]
     fn serialize_parsing() {
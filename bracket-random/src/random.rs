@@ -30,14 +30,20 @@ fn get_seed() -> u64 {
     }
 }
 
+/// A dice-oriented wrapper around a random number generator. The backing
+/// algorithm is any `rand` `RngCore` implementation; it defaults to
+/// `XorShiftRng`, and `new`/`seeded` always use that default, so existing
+/// seeds keep reproducing the same streams. Use `with_rng` to substitute a
+/// different generator (e.g. a cryptographically stronger one) while keeping
+/// the `roll_str`/`range`/etc. API.
 #[derive(Clone)]
 #[cfg_attr(
     feature = "serde",
     derive(Serialize, Deserialize),
     serde(crate = "serde_crate")
 )]
-pub struct RandomNumberGenerator {
-    rng: XorShiftRng,
+pub struct RandomNumberGenerator<R: RngCore = XorShiftRng> {
+    rng: R,
 }
 
 impl RandomNumberGenerator {
@@ -48,11 +54,24 @@ impl RandomNumberGenerator {
         RandomNumberGenerator { rng }
     }
 
-    /// Creates a new RNG from a specific seed
+    /// Creates a new RNG from a specific seed. The backing algorithm is
+    /// pinned to `rand_xorshift`'s `XorShiftRng`, which is a pure-software
+    /// implementation with no platform-dependent behavior: a given seed
+    /// produces an identical stream of numbers on every target, including
+    /// WebAssembly. Shared seeds can therefore be relied upon to reproduce
+    /// the same dungeon everywhere.
     pub fn seeded(seed: u64) -> RandomNumberGenerator {
         let rng: XorShiftRng = SeedableRng::seed_from_u64(seed);
         RandomNumberGenerator { rng }
     }
+}
+
+impl<R: RngCore> RandomNumberGenerator<R> {
+    /// Wraps a caller-supplied generator, so the dice API runs on top of a
+    /// different algorithm: `RandomNumberGenerator::with_rng(StdRng::seed_from_u64(1))`.
+    pub fn with_rng(rng: R) -> Self {
+        Self { rng }
+    }
 
     /// Returns a random value of whatever type you specify
     pub fn rand<T>(&mut self) -> T
@@ -72,16 +91,63 @@ impl RandomNumberGenerator {
         self.rng.gen_range(min..max)
     }
 
+    /// Returns a random value from an inclusive range, so `range_inclusive(1..=6)`
+    /// can return any of 1, 2, 3, 4, 5 or 6. Unlike `range`, both ends of the
+    /// range are included.
+    pub fn range_inclusive<T>(&mut self, range: std::ops::RangeInclusive<T>) -> T
+    where
+        T: rand::distributions::uniform::SampleUniform + PartialOrd,
+    {
+        self.rng.gen_range(range)
+    }
+
     /// Rolls dice, using the classic 3d6 type of format: n is the number of dice, die_type is the size of the dice.
     pub fn roll_dice(&mut self, n: i32, die_type: i32) -> i32 {
         (0..n).map(|_| self.range(1, die_type + 1)).sum()
     }
 
+    /// Rolls a percentile die, returning a value from 1 to 100 inclusive.
+    /// Equivalent to `roll_dice(1, 100)`.
+    pub fn roll_percentile(&mut self) -> i32 {
+        self.roll_dice(1, 100)
+    }
+
+    /// Rolls a percentile die and returns true if the result is less than
+    /// or equal to `target` - the classic "roll under" percentage check.
+    pub fn percentile_check(&mut self, target: i32) -> bool {
+        self.roll_percentile() <= target
+    }
+
     /// Returns the RNG's next unsigned-64 type
     pub fn next_u64(&mut self) -> u64 {
         self.rng.next_u64()
     }
 
+    /// Deterministically derives an independent child generator, advancing
+    /// this generator by one `u64`. Forking from the same parent state
+    /// always yields the same child, so worker threads can each take a fork
+    /// and generate reproducibly in parallel without sharing a mutable RNG.
+    pub fn fork(&mut self) -> Self
+    where
+        R: SeedableRng,
+    {
+        Self {
+            rng: R::seed_from_u64(self.next_u64()),
+        }
+    }
+
+    /// Returns a stable `u64` seed derived from the generator's current
+    /// state, without advancing it. Calling it repeatedly returns the same
+    /// value until the generator is next used. This lets a single master
+    /// seed drive both random numbers and noise, e.g.
+    /// `FastNoise::seeded(rng.noise_seed())` from `bracket-noise`.
+    pub fn noise_seed(&self) -> u64
+    where
+        R: Clone,
+    {
+        self.rng.clone().next_u64()
+    }
+
     /// Rolls dice based on a DiceType struct, including application of the bonus
     #[cfg(feature = "parsing")]
     pub fn roll(&mut self, dice: DiceType) -> i32 {
@@ -97,6 +163,17 @@ impl RandomNumberGenerator {
         }
     }
 
+    /// Returns an iterator that yields `n` rolls of a dice expression such
+    /// as "3d6+1", parsing the expression only once. An unparseable
+    /// expression yields no results; use `roll_str` first if you need the
+    /// parse error. Handy for statistics:
+    /// `rng.rolls("3d6", 200_000).for_each(|r| counts[r as usize] += 1);`
+    #[cfg(feature = "parsing")]
+    pub fn rolls<'a>(&'a mut self, expr: &str, n: usize) -> impl Iterator<Item = i32> + 'a {
+        let dice = parse_dice_string(expr).ok();
+        (0..n).filter_map(move |_| dice.map(|dt| self.roll(dt)))
+    }
+
     /// Returns a random index into a slice
     pub fn random_slice_index<T>(&mut self, slice: &[T]) -> Option<usize> {
         if slice.is_empty() {
@@ -125,9 +202,39 @@ impl RandomNumberGenerator {
         }
     }
 
+    /// Returns `n` distinct indices drawn from `0..len`, in random order,
+    /// using a partial Fisher-Yates shuffle. If `n >= len`, all indices are
+    /// returned, shuffled.
+    pub fn sample_n(&mut self, len: usize, n: usize) -> Vec<usize> {
+        let mut indices: Vec<usize> = (0..len).collect();
+        let n = usize::min(n, len);
+        for i in 0..n {
+            let j = self.range(i, len);
+            indices.swap(i, j);
+        }
+        indices.truncate(n);
+        indices
+    }
+
+    /// Picks one item uniformly at random from an iterator in a single pass,
+    /// using reservoir sampling (Algorithm R), so the stream never needs to
+    /// be buffered. Returns `None` for an empty iterator; a one-item
+    /// iterator always returns that item.
+    pub fn reservoir_sample<T, I: Iterator<Item = T>>(&mut self, iter: I) -> Option<T> {
+        let mut chosen = None;
+        for (seen, item) in iter.enumerate() {
+            // Replace the reservoir with probability 1/(seen+1): certain for
+            // the first item, ever rarer afterwards.
+            if self.range(0, seen + 1) == 0 {
+                chosen = Some(item);
+            }
+        }
+        chosen
+    }
+
     /// Get underlying RNG implementation for use in traits / algorithms exposed by
     /// other crates (eg. `rand` itself)
-    pub fn get_rng(&mut self) -> &mut XorShiftRng {
+    pub fn get_rng(&mut self) -> &mut R {
         &mut self.rng
     }
 }
@@ -169,6 +276,143 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_range_inclusive() {
+        let mut rng = RandomNumberGenerator::new();
+        for _ in 0..100 {
+            let n = rng.range_inclusive(1..=6);
+            assert!((1..=6).contains(&n));
+        }
+        assert_eq!(rng.range_inclusive(3..=3), 3);
+    }
+
+    #[test]
+    fn test_percentile_roll() {
+        let mut rng = RandomNumberGenerator::new();
+        for _ in 0..100 {
+            let n = rng.roll_percentile();
+            assert!(n > 0 && n < 101);
+        }
+    }
+
+    #[test]
+    fn test_percentile_check_bounds() {
+        let mut rng = RandomNumberGenerator::new();
+        for _ in 0..100 {
+            assert!(rng.percentile_check(100));
+            assert!(!rng.percentile_check(0));
+        }
+    }
+
+    #[test]
+    // Known-answer test: `seeded` is documented to produce identical streams
+    // on every platform. If this fails, the backing algorithm changed and
+    // the reproducibility guarantee is broken - don't just update the
+    // numbers without bumping the major version.
+    fn seeded_known_sequence() {
+        let mut rng = RandomNumberGenerator::seeded(2023);
+        let sequence: Vec<u64> = (0..6).map(|_| rng.next_u64()).collect();
+        assert_eq!(
+            sequence,
+            vec![
+                14_988_211_882_155_200_577,
+                13_096_225_448_372_154_388,
+                17_283_654_234_995_673_350,
+                1_062_774_789_331_056_011,
+                13_326_944_687_536_708_386,
+                9_397_512_634_536_171_053,
+            ]
+        );
+    }
+
+    #[test]
+    fn test_rolls_iterator() {
+        let mut rng = RandomNumberGenerator::new();
+        let results: Vec<i32> = rng.rolls("3d6", 100).collect();
+        assert_eq!(results.len(), 100);
+        assert!(results.iter().all(|n| (3..=18).contains(n)));
+        assert_eq!(rng.rolls("blah", 100).count(), 0);
+    }
+
+    #[test]
+    fn test_fork_deterministic() {
+        let mut a = RandomNumberGenerator::seeded(7);
+        let mut b = RandomNumberGenerator::seeded(7);
+        let mut child_a = a.fork();
+        let mut child_b = b.fork();
+        // Same parent state yields the same child stream.
+        for _ in 0..10 {
+            assert_eq!(child_a.next_u64(), child_b.next_u64());
+        }
+        // Forking advances the parent, so a second fork differs.
+        let mut second = a.fork();
+        assert_ne!(child_b.noise_seed(), second.noise_seed());
+        assert_ne!(second.next_u64(), child_b.next_u64());
+    }
+
+    #[test]
+    fn test_noise_seed_stable() {
+        let mut rng = RandomNumberGenerator::seeded(42);
+        let seed = rng.noise_seed();
+        assert_eq!(seed, rng.noise_seed());
+        assert_eq!(seed, RandomNumberGenerator::seeded(42).noise_seed());
+        rng.roll_dice(1, 6);
+        assert_ne!(seed, rng.noise_seed());
+    }
+
+    #[test]
+    fn test_sample_n_distinct() {
+        let mut rng = RandomNumberGenerator::new();
+        for _ in 0..100 {
+            let sample = rng.sample_n(10, 3);
+            assert_eq!(sample.len(), 3);
+            let unique: std::collections::HashSet<usize> = sample.iter().copied().collect();
+            assert_eq!(unique.len(), 3);
+            assert!(sample.iter().all(|i| *i < 10));
+        }
+    }
+
+    #[test]
+    fn test_sample_n_oversized() {
+        let mut rng = RandomNumberGenerator::new();
+        let mut sample = rng.sample_n(5, 100);
+        sample.sort_unstable();
+        assert_eq!(sample, vec![0, 1, 2, 3, 4]);
+        assert!(rng.sample_n(0, 3).is_empty());
+    }
+
+    #[test]
+    fn test_with_rng_matches_seeded_default() {
+        use rand::SeedableRng;
+        let mut a = RandomNumberGenerator::seeded(42);
+        let mut b =
+            RandomNumberGenerator::with_rng(rand_xorshift::XorShiftRng::seed_from_u64(42));
+        // The full dice API runs on top of whatever generator was supplied.
+        for _ in 0..5 {
+            assert_eq!(a.next_u64(), b.next_u64());
+            assert_eq!(a.roll_dice(3, 6), b.roll_dice(3, 6));
+        }
+    }
+
+    #[test]
+    fn test_reservoir_sample_edges() {
+        let mut rng = RandomNumberGenerator::new();
+        assert_eq!(rng.reservoir_sample(std::iter::empty::<i32>()), None);
+        assert_eq!(rng.reservoir_sample(std::iter::once(42)), Some(42));
+    }
+
+    #[test]
+    fn test_reservoir_sample_covers_all_items() {
+        let mut rng = RandomNumberGenerator::new();
+        let mut seen = std::collections::HashSet::new();
+        for _ in 0..200 {
+            let pick = rng.reservoir_sample(0..5).unwrap();
+            assert!((0..5).contains(&pick));
+            seen.insert(pick);
+        }
+        assert_eq!(seen.len(), 5);
+    }
+
     #[test]
     fn random_slice_index_empty() {
         let mut rng = RandomNumberGenerator::new();
@@ -0,0 +1,120 @@
+use crate::prelude::RandomNumberGenerator;
+
+/// A reusable weighted random table. Weights are accumulated as entries are
+/// added, so rolling is a single random number and a scan - no per-roll
+/// allocation or rebuild. Entries with a zero weight are stored but never
+/// returned by `roll`.
+///
+/// ```rust
+/// use bracket_random::prelude::*;
+/// let mut rng = RandomNumberGenerator::new();
+/// let mut table = WeightedTable::new();
+/// table.add("common", 10);
+/// table.add("rare", 1);
+/// let result = table.roll(&mut rng);
+/// assert!(result.is_some());
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct WeightedTable<T> {
+    entries: Vec<(T, i32)>,
+    cumulative: Vec<i32>,
+    total_weight: i32,
+}
+
+impl<T> WeightedTable<T> {
+    /// Creates an empty weighted table.
+    pub fn new() -> Self {
+        Self {
+            entries: Vec::new(),
+            cumulative: Vec::new(),
+            total_weight: 0,
+        }
+    }
+
+    /// Adds an item with the given weight. Negative weights are treated as
+    /// zero. Returns `&mut self` so calls can be chained.
+    pub fn add(&mut self, item: T, weight: i32) -> &mut Self {
+        let weight = weight.max(0);
+        self.total_weight += weight;
+        self.entries.push((item, weight));
+        self.cumulative.push(self.total_weight);
+        self
+    }
+
+    /// Rolls on the table, returning a reference to the selected item. The
+    /// chance of each item is its weight divided by the total weight. Returns
+    /// `None` if the table is empty or every entry has zero weight.
+    pub fn roll<'a>(&'a self, rng: &mut RandomNumberGenerator) -> Option<&'a T> {
+        if self.total_weight == 0 {
+            return None;
+        }
+        let roll = rng.range(0, self.total_weight);
+        let index = match self.cumulative.binary_search(&roll) {
+            // Cumulative sums are the first value belonging to the *next*
+            // entry, so an exact match advances past it; zero-weight entries
+            // repeat the previous sum and are skipped the same way.
+            Ok(i) => {
+                let mut i = i + 1;
+                while self.cumulative[i] == roll {
+                    i += 1;
+                }
+                i
+            }
+            Err(i) => i,
+        };
+        Some(&self.entries[index].0)
+    }
+
+    /// Returns the number of entries in the table, including zero weights.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns true if the table has no entries.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// The sum of all entry weights.
+    pub fn total_weight(&self) -> i32 {
+        self.total_weight
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::{RandomNumberGenerator, WeightedTable};
+
+    #[test]
+    fn empty_table_rolls_none() {
+        let mut rng = RandomNumberGenerator::new();
+        let table: WeightedTable<i32> = WeightedTable::new();
+        assert!(table.roll(&mut rng).is_none());
+    }
+
+    #[test]
+    fn zero_weights_never_roll() {
+        let mut rng = RandomNumberGenerator::new();
+        let mut table = WeightedTable::new();
+        table.add("never", 0);
+        assert!(table.roll(&mut rng).is_none());
+        table.add("always", 1).add("also never", 0);
+        for _ in 0..100 {
+            assert_eq!(*table.roll(&mut rng).unwrap(), "always");
+        }
+    }
+
+    #[test]
+    fn weights_are_respected() {
+        let mut rng = RandomNumberGenerator::seeded(42);
+        let mut table = WeightedTable::new();
+        table.add("common", 99).add("rare", 1);
+        let mut common = 0;
+        for _ in 0..1000 {
+            if *table.roll(&mut rng).unwrap() == "common" {
+                common += 1;
+            }
+        }
+        assert!(common > 900);
+    }
+}
@@ -2,13 +2,14 @@
 #[allow(unused_imports)]
 use crate::{
     prelude::{
-        init_raw, BEvent, CharacterTranslationMode, Console, FlexiConsole, Font, FontCharType,
-        GameState, InitHints, Radians, RenderSprite, Shader, SimpleConsole, SpriteConsole,
-        SpriteSheet, TextAlign, VirtualKeyCode, XpFile, XpLayer, BACKEND, INPUT,
+        init_raw, AnimatedGlyph, BEvent, CharacterTranslationMode, Console, FlexiConsole, Font,
+        FontCharType, GameState, InitHints, Radians, RenderSprite, Shader, SimpleConsole,
+        SparseConsole, SpriteConsole, SpriteSheet, TextAlign, VirtualConsole, VirtualKeyCode,
+        XpFile, XpLayer, BACKEND, INPUT,
     },
     BResult,
 };
-use bracket_color::prelude::RGBA;
+use bracket_color::prelude::{ColorPair, RGBA};
 use bracket_geometry::prelude::{Point, PointF, Rect};
 use parking_lot::Mutex;
 use std::convert::*;
@@ -57,6 +58,36 @@ lazy_static! {
     pub static ref BACKEND_INTERNAL: Mutex<BTermInternal> = Mutex::new(BTermInternal::new());
 }
 
+/// A snapshot of the OS window's size (in physical pixels), outer position
+/// and fullscreen state. Capture it with `BTerm::window_state()`, persist it
+/// (it derives `Serialize`/`Deserialize` with the `serde` feature), and
+/// restore it with `InitHints::with_window_state`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct WindowState {
+    pub width: u32,
+    pub height: u32,
+    pub x: i32,
+    pub y: i32,
+    pub fullscreen: bool,
+}
+
+/// Description of an attached monitor, as reported by the windowing
+/// back-end. Enumerate them with `BTerm::available_monitors()`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct MonitorInfo {
+    /// Human-readable monitor name, if the platform provides one.
+    pub name: Option<String>,
+    /// Monitor width in physical pixels.
+    pub width: u32,
+    /// Monitor height in physical pixels.
+    pub height: u32,
+    /// Refresh rate in millihertz (e.g. 60000 for 60Hz), if known.
+    pub refresh_rate_millihertz: Option<u32>,
+    /// The monitor's DPI scale factor.
+    pub scale_factor: f64,
+}
+
 /// A BTerm context.
 #[derive(Clone, Debug)]
 pub struct BTerm {
@@ -78,7 +109,17 @@ pub struct BTerm {
     pub post_scanlines: bool,
     pub post_screenburn: bool,
     pub screen_burn_color: bracket_color::prelude::RGB,
+    pub background_clear: RGBA,
     pub mouse_visible: bool,
+    pub window_always_on_top: bool,
+    pub window_decorations: bool,
+    pub mouse_grabbed: bool,
+    pub window_position: (i32, i32),
+    pub fullscreen: bool,
+    pub fullscreen_monitor: Option<usize>,
+    pub run_time_seconds: f32,
+    pub tab_width: Option<u32>,
+    pub text_outline: std::collections::HashMap<usize, RGBA>,
 }
 
 impl BTerm {
@@ -287,6 +328,17 @@ impl BTerm {
         Point::new(char_pos.0, char_pos.1)
     }
 
+    /// Translates the current physical mouse position into the tile
+    /// coordinate space of the specified console layer, accounting for
+    /// scaling and gutters. Positions outside the console are clamped to the
+    /// nearest edge cell, so the result is always a valid cell coordinate.
+    pub fn mouse_tile(&self, console: usize) -> Point {
+        let bi = BACKEND_INTERNAL.lock();
+        let char_pos = self.pixel_to_char_pos(self.mouse_pos, &bi.consoles[console].console);
+
+        Point::new(char_pos.0, char_pos.1)
+    }
+
     /// Tells the game to quit
     pub fn quit(&mut self) {
         self.quitting = true;
@@ -319,6 +371,75 @@ impl BTerm {
         xp
     }
 
+    /// Dumps a console's glyphs as a string, one line per row (rows separated
+    /// with `\n`). Colors are discarded, and blank cells are rendered as spaces.
+    /// Useful for snapshot/golden testing.
+    pub fn to_text(&self, console: usize) -> String {
+        let bi = BACKEND_INTERNAL.lock();
+        let layer = bi.consoles[console].console.to_xp_layer();
+
+        let mut result = String::with_capacity((layer.width + 1) * layer.height);
+        for y in 0..layer.height {
+            if y > 0 {
+                result.push('\n');
+            }
+            for x in 0..layer.width {
+                let glyph = layer.get(x, y).unwrap().ch;
+                if glyph == 0 || glyph == 32 {
+                    result.push(' ');
+                } else {
+                    result.push(crate::prelude::to_char(glyph as u8));
+                }
+            }
+        }
+        result
+    }
+
+    /// Paints a console's glyphs from a text dump in the format produced by
+    /// `to_text`: one line per row, top to bottom. Colors are left at the
+    /// console's defaults; lines beyond the console's extent are ignored.
+    pub fn from_text(&mut self, console: usize, text: &str) {
+        let mut bi = BACKEND_INTERNAL.lock();
+        for (y, line) in text.split('\n').enumerate() {
+            bi.consoles[console].console.print(0, y as i32, line);
+        }
+    }
+
+    /// Iterates every stored cell of a console in row-major order, yielding
+    /// the cell's position, glyph, foreground and background. Simple and
+    /// virtual consoles yield every cell; sparse consoles yield only the
+    /// cells that have been written. Sprite and fancy consoles store
+    /// free-floating entries rather than a cell grid, and yield nothing.
+    pub fn iter_cells(
+        &self,
+        console: usize,
+    ) -> impl Iterator<Item = (Point, FontCharType, RGBA, RGBA)> {
+        let bi = BACKEND_INTERNAL.lock();
+        let cons_any = bi.consoles[console].console.as_any();
+        let mut cells: Vec<(Point, FontCharType, RGBA, RGBA)> = Vec::new();
+        if let Some(sc) = cons_any.downcast_ref::<SimpleConsole>() {
+            cells.extend(sc.tiles.iter().enumerate().map(|(idx, t)| {
+                let x = (idx % sc.width as usize) as i32;
+                let y = (idx / sc.width as usize) as i32;
+                (Point::new(x, y), t.glyph, t.fg, t.bg)
+            }));
+        } else if let Some(vc) = cons_any.downcast_ref::<VirtualConsole>() {
+            cells.extend(vc.tiles.iter().enumerate().map(|(idx, t)| {
+                let x = (idx % vc.width as usize) as i32;
+                let y = (idx / vc.width as usize) as i32;
+                (Point::new(x, y), t.glyph, t.fg, t.bg)
+            }));
+        } else if let Some(sc) = cons_any.downcast_ref::<SparseConsole>() {
+            cells.extend(sc.tiles.iter().map(|t| {
+                let x = (t.idx % sc.width as usize) as i32;
+                let y = (t.idx / sc.width as usize) as i32;
+                (Point::new(x, y), t.glyph, t.fg, t.bg)
+            }));
+            cells.sort_by_key(|(p, ..)| (p.y, p.x));
+        }
+        cells.into_iter()
+    }
+
     /// Enable scanlines post-processing effect.
     pub fn with_post_scanlines(&mut self, with_burn: bool) {
         self.post_scanlines = true;
@@ -330,11 +451,137 @@ impl BTerm {
         self.screen_burn_color = color;
     }
 
+    /// Change the color used to clear the backing buffer each frame.
+    /// Defaults to opaque black; a translucent color lets window content
+    /// behind the terminal show through on backends that support it.
+    pub fn set_background_clear(&mut self, color: RGBA) {
+        self.background_clear = color;
+    }
+
+    /// Keep the window above all others (or restore normal stacking).
+    /// Useful for floating overlays; independent of fullscreen and applied
+    /// continuously, so it survives resizes.
+    pub fn set_always_on_top(&mut self, always_on_top: bool) {
+        self.window_always_on_top = always_on_top;
+    }
+
+    /// Show or hide the window's decorations (title bar and borders).
+    pub fn set_decorations(&mut self, decorations: bool) {
+        self.window_decorations = decorations;
+    }
+
+    /// Enumerate the attached monitors: name, size, refresh rate and scale
+    /// factor. Usable both before and during the main loop. Only the native
+    /// OpenGL back-end reports monitors; the others return an empty list.
+    pub fn available_monitors(&self) -> Vec<MonitorInfo> {
+        #[cfg(all(feature = "opengl", not(target_arch = "wasm32")))]
+        {
+            crate::hal::BACKEND.lock().monitors.clone()
+        }
+        #[cfg(not(all(feature = "opengl", not(target_arch = "wasm32"))))]
+        {
+            Vec::new()
+        }
+    }
+
+    /// Sets a static background image for the specified console layer,
+    /// loaded from a file path (or an embedded resource). It is rendered
+    /// into the backing buffer before the glyphs, stretched to the window,
+    /// and picks up the same scaling. Only supported on the native OpenGL
+    /// back-end; consoles without a background render exactly as before.
+    #[cfg(all(feature = "opengl", not(target_arch = "wasm32")))]
+    pub fn set_console_background_image<S: ToString>(&mut self, console: usize, path: S) {
+        crate::hal::BACKEND
+            .lock()
+            .background_requests
+            .push((console, Some(path.to_string())));
+    }
+
+    /// Removes a console background image previously set with
+    /// `set_console_background_image`.
+    #[cfg(all(feature = "opengl", not(target_arch = "wasm32")))]
+    pub fn clear_console_background_image(&mut self, console: usize) {
+        crate::hal::BACKEND
+            .lock()
+            .background_requests
+            .push((console, None));
+    }
+
+    /// Enter or leave borderless fullscreen at runtime. `monitor` is an
+    /// index into `available_monitors()`; `None` uses the monitor the window
+    /// is currently on. Applied by the main loop on the next frame.
+    pub fn set_fullscreen(&mut self, fullscreen: bool, monitor: Option<usize>) {
+        self.fullscreen = fullscreen;
+        self.fullscreen_monitor = monitor;
+    }
+
+    /// Capture the current window size, position and fullscreen state as a
+    /// `WindowState`, suitable for persisting between sessions (it is
+    /// serde-serializable with the `serde` feature enabled). Restore it on
+    /// the next run with `InitHints::with_window_state`.
+    pub fn window_state(&self) -> WindowState {
+        WindowState {
+            width: self.width_pixels,
+            height: self.height_pixels,
+            x: self.window_position.0,
+            y: self.window_position.1,
+            fullscreen: self.fullscreen,
+        }
+    }
+
+    /// Changes the frames-per-second cap at runtime, overriding the value
+    /// set at initialization. `None` removes the cap, restoring the default
+    /// unthrottled behavior. Handy for dropping to a low-power framerate on
+    /// menu screens and raising it again during gameplay.
+    pub fn set_target_fps(&mut self, fps: Option<f32>) {
+        let frame_sleep_time = fps.map(|f| 1.0 / f);
+        #[cfg(all(any(feature = "opengl", feature = "webgpu"), not(target_arch = "wasm32")))]
+        {
+            crate::hal::BACKEND.lock().frame_sleep_time =
+                crate::hal::convert_fps_to_wait(frame_sleep_time);
+        }
+        #[cfg(all(feature = "opengl", target_arch = "wasm32"))]
+        {
+            crate::hal::BACKEND.lock().frame_sleep_time = frame_sleep_time;
+        }
+        #[cfg(all(
+            not(feature = "opengl"),
+            not(feature = "webgpu"),
+            any(feature = "curses", feature = "cross_term")
+        ))]
+        {
+            crate::hal::BACKEND.lock().frame_sleep_time =
+                crate::hal::convert_fps_to_wait(frame_sleep_time);
+        }
+        #[cfg(not(any(
+            feature = "opengl",
+            feature = "webgpu",
+            feature = "curses",
+            feature = "cross_term"
+        )))]
+        let _ = frame_sleep_time; // The dummy back-end has no frame timing.
+    }
+
+    /// Grab (lock) the mouse cursor, hiding it and emitting
+    /// `BEvent::MouseDelta` events with relative motion. Pass `false` to
+    /// release the grab and restore normal cursor behavior and visibility.
+    pub fn set_cursor_grab(&mut self, grabbed: bool) {
+        self.mouse_grabbed = grabbed;
+    }
+
     // Set the mouse cursor visibility
     pub fn with_mouse_visibility(&mut self, with_visibility: bool) {
         self.mouse_visible = with_visibility;
     }
 
+    /// Returns true if the specified key is currently held down. Unlike the
+    /// event-based `key` field, this is level-triggered: it stays true for as
+    /// long as the key is held, making it suitable for smooth movement. Held
+    /// keys are cleared when the window loses focus.
+    pub fn key_down(&self, key: VirtualKeyCode) -> bool {
+        INPUT.lock().is_key_pressed(key)
+    }
+
     /// Internal: mark a key press
     pub(crate) fn on_key(&mut self, key: VirtualKeyCode, scan_code: u32, pressed: bool) {
         let mut input = INPUT.lock();
@@ -349,6 +596,9 @@ impl BTerm {
             key,
             scan_code,
             pressed,
+            shift: self.shift,
+            control: self.control,
+            alt: self.alt,
         });
     }
 
@@ -438,6 +688,21 @@ impl BTerm {
             .cls_bg(background.into());
     }
 
+    /// Request that the active console clear just the cells inside `bounds`,
+    /// resetting them to a space with the default white-on-black colors (the
+    /// same state `cls` leaves cells in). The rectangle is clipped to the
+    /// console; cells outside it are untouched.
+    pub fn cls_region(&mut self, bounds: Rect) {
+        BACKEND_INTERNAL.lock().consoles[self.active_console]
+            .console
+            .fill_region(
+                bounds,
+                32,
+                RGBA::from_u8(255, 255, 255, 255),
+                RGBA::from_u8(0, 0, 0, 255),
+            );
+    }
+
     /// Print a string to the active console.
     pub fn print<S, X, Y>(&mut self, x: X, y: Y, output: S)
     where
@@ -445,13 +710,109 @@ impl BTerm {
         X: TryInto<i32>,
         Y: TryInto<i32>,
     {
-        BACKEND_INTERNAL.lock().consoles[self.active_console]
-            .console
-            .print(
-                x.try_into().ok().expect("Must be i32 convertible"),
-                y.try_into().ok().expect("Must be i32 convertible"),
-                &output.to_string(),
-            );
+        let x = x.try_into().ok().expect("Must be i32 convertible");
+        let y = y.try_into().ok().expect("Must be i32 convertible");
+        let text = output.to_string();
+        let shadow = self.text_outline.get(&self.active_console).copied();
+        if let Some(tab_width) = self.tab_width.filter(|w| *w > 0) {
+            // The shadow pass expands tabs too, so it stays aligned with the
+            // main pass.
+            if let Some(shadow) = shadow {
+                self.print_with_tabs(
+                    x + 1,
+                    y + 1,
+                    &text,
+                    tab_width as i32,
+                    Some((shadow, RGBA::from_f32(0.0, 0.0, 0.0, 0.0))),
+                );
+            }
+            self.print_with_tabs(x, y, &text, tab_width as i32, None);
+        } else {
+            let mut bi = BACKEND_INTERNAL.lock();
+            if let Some(shadow) = shadow {
+                bi.consoles[self.active_console].console.print_color(
+                    x + 1,
+                    y + 1,
+                    shadow,
+                    RGBA::from_f32(0.0, 0.0, 0.0, 0.0),
+                    &text,
+                );
+            }
+            bi.consoles[self.active_console].console.print(x, y, &text);
+        }
+    }
+
+    /// Returns the number of cells `print` would consume for `text`: one cell
+    /// per character after CP437 conversion. Doryen-style `#[color]` markup
+    /// (as consumed by `printer`) is stripped before counting, so the result
+    /// is the visible width either path would render. Useful for
+    /// right-alignment and sizing tooltip boxes.
+    pub fn measure_text(&self, text: &str) -> i32 {
+        if text.contains("#[") {
+            crate::prelude::parse_colored_text(text)
+                .iter()
+                .map(|(span, _)| span.chars().count())
+                .sum::<usize>() as i32
+        } else {
+            text.chars().count() as i32
+        }
+    }
+
+    /// Enables or disables drop-shadow text for a console. When a color is
+    /// set, subsequent `print`, `print_color` and `set` calls on that console
+    /// first paint the glyphs offset one cell down-right in the given color,
+    /// then paint them normally on top - making small fonts readable over
+    /// busy backgrounds. `None` disables the effect; disabled consoles pay
+    /// only a hash lookup.
+    pub fn set_text_outline(&mut self, console: usize, color: Option<RGBA>) {
+        match color {
+            Some(c) => {
+                self.text_outline.insert(console, c);
+            }
+            None => {
+                self.text_outline.remove(&console);
+            }
+        }
+    }
+
+    /// Opt in to tab expansion in `print` (and the batched `Print` command):
+    /// a `\t` advances the cursor to the next multiple of `width` columns
+    /// instead of rendering glyph 0x09. The conventional width is 8. `None`
+    /// (the default) keeps the old behavior of printing the tab glyph, for
+    /// code that relies on it.
+    pub fn set_tab_width(&mut self, width: Option<u32>) {
+        self.tab_width = width;
+    }
+
+    /// Prints `text`, expanding each tab by advancing the cursor to the next
+    /// multiple of `tab_width`. Skipped cells are left untouched. When
+    /// `color` is given the segments print in that fg/bg pair (used by the
+    /// drop-shadow pass); otherwise the console's defaults apply.
+    fn print_with_tabs(
+        &mut self,
+        x: i32,
+        y: i32,
+        text: &str,
+        tab_width: i32,
+        color: Option<(RGBA, RGBA)>,
+    ) {
+        let mut bi = BACKEND_INTERNAL.lock();
+        let console = &mut bi.consoles[self.active_console].console;
+        let mut cursor_x = x;
+        let mut first = true;
+        for segment in text.split('\t') {
+            if !first {
+                cursor_x = ((cursor_x / tab_width) + 1) * tab_width;
+            }
+            first = false;
+            if !segment.is_empty() {
+                match color {
+                    Some((fg, bg)) => console.print_color(cursor_x, y, fg, bg, segment),
+                    None => console.print(cursor_x, y, segment),
+                }
+                cursor_x += segment.chars().count() as i32;
+            }
+        }
     }
 
     /// Print a string to the active console, in color.
@@ -469,15 +830,22 @@ impl BTerm {
         X: TryInto<i32>,
         Y: TryInto<i32>,
     {
-        BACKEND_INTERNAL.lock().consoles[self.active_console]
-            .console
-            .print_color(
-                x.try_into().ok().expect("Must be i32 convertible"),
-                y.try_into().ok().expect("Must be i32 convertible"),
-                fg.into(),
-                bg.into(),
-                &output.to_string(),
+        let x = x.try_into().ok().expect("Must be i32 convertible");
+        let y = y.try_into().ok().expect("Must be i32 convertible");
+        let text = output.to_string();
+        let mut bi = BACKEND_INTERNAL.lock();
+        if let Some(&shadow) = self.text_outline.get(&self.active_console) {
+            bi.consoles[self.active_console].console.print_color(
+                x + 1,
+                y + 1,
+                shadow,
+                RGBA::from_f32(0.0, 0.0, 0.0, 0.0),
+                &text,
             );
+        }
+        bi.consoles[self.active_console]
+            .console
+            .print_color(x, y, fg.into(), bg.into(), &text);
     }
 
     /// Set a single tile located at x/y to the specified foreground/background colors, and glyph.
@@ -495,18 +863,54 @@ impl BTerm {
         X: TryInto<i32>,
         Y: TryInto<i32>,
     {
-        BACKEND_INTERNAL.lock().consoles[self.active_console]
-            .console
-            .set(
-                x.try_into().ok().expect("Must be i32 convertible"),
-                y.try_into().ok().expect("Must be i32 convertible"),
-                fg.into(),
-                bg.into(),
-                glyph.try_into().ok().expect("Must be u16 convertible"),
+        let x = x.try_into().ok().expect("Must be i32 convertible");
+        let y = y.try_into().ok().expect("Must be i32 convertible");
+        let glyph = glyph.try_into().ok().expect("Must be u16 convertible");
+        let mut bi = BACKEND_INTERNAL.lock();
+        if let Some(&shadow) = self.text_outline.get(&self.active_console) {
+            bi.consoles[self.active_console].console.set(
+                x + 1,
+                y + 1,
+                shadow,
+                RGBA::from_f32(0.0, 0.0, 0.0, 0.0),
+                glyph,
             );
+        }
+        bi.consoles[self.active_console]
+            .console
+            .set(x, y, fg.into(), bg.into(), glyph);
     }
 
-    /// Set a tile with "fancy" additional attributes
+    /// Set a single tile located at x/y to an animated glyph, selecting the
+    /// frame to display from the context's running time. Call it every
+    /// frame; the animation advances on its own.
+    pub fn set_animated<COLOR, COLOR2, X, Y>(
+        &mut self,
+        x: X,
+        y: Y,
+        fg: COLOR,
+        bg: COLOR2,
+        anim: &AnimatedGlyph,
+    ) where
+        COLOR: Into<RGBA>,
+        COLOR2: Into<RGBA>,
+        X: TryInto<i32>,
+        Y: TryInto<i32>,
+    {
+        let glyph = anim.frame_at(self.run_time_seconds);
+        self.set(
+            x.try_into().ok().expect("Must be i32 convertible"),
+            y.try_into().ok().expect("Must be i32 convertible"),
+            fg.into(),
+            bg.into(),
+            glyph,
+        );
+    }
+
+    /// Set a tile with "fancy" additional attributes. Negative scale
+    /// components mirror the glyph around its center: `scale.x = -1.0`
+    /// flips horizontally, `scale.y = -1.0` vertically. See
+    /// `set_fancy_mirrored` for an explicit flag-based version.
     #[cfg(any(feature = "opengl", feature = "webgpu"))]
     #[allow(clippy::too_many_arguments)]
     pub fn set_fancy<COLOR, COLOR2, GLYPH, ANGLE>(
@@ -559,6 +963,35 @@ impl BTerm {
         // Does nothing
     }
 
+    /// `set_fancy` with explicit mirroring: flips the glyph horizontally
+    /// and/or vertically around its center by negating the matching scale
+    /// component. Handy for reusing one directional sprite glyph for both
+    /// facings.
+    #[allow(clippy::too_many_arguments)]
+    pub fn set_fancy_mirrored<COLOR, COLOR2, GLYPH, ANGLE>(
+        &mut self,
+        position: PointF,
+        z_order: i32,
+        rotation: ANGLE,
+        scale: PointF,
+        mirror_horizontal: bool,
+        mirror_vertical: bool,
+        fg: COLOR,
+        bg: COLOR2,
+        glyph: GLYPH,
+    ) where
+        COLOR: Into<RGBA>,
+        COLOR2: Into<RGBA>,
+        GLYPH: TryInto<FontCharType>,
+        ANGLE: Into<Radians>,
+    {
+        let scale = PointF {
+            x: if mirror_horizontal { -scale.x } else { scale.x },
+            y: if mirror_vertical { -scale.y } else { scale.y },
+        };
+        self.set_fancy(position, z_order, rotation, scale, fg, bg, glyph);
+    }
+
     /// Sets the background color only of a specified tile.
     pub fn set_bg<COLOR, X, Y>(&mut self, x: X, y: Y, bg: COLOR)
     where
@@ -575,6 +1008,23 @@ impl BTerm {
             );
     }
 
+    /// Sets the foreground color only of a specified tile, leaving the glyph
+    /// and background as they are.
+    pub fn set_fg<COLOR, X, Y>(&mut self, x: X, y: Y, fg: COLOR)
+    where
+        COLOR: Into<RGBA>,
+        X: TryInto<i32>,
+        Y: TryInto<i32>,
+    {
+        BACKEND_INTERNAL.lock().consoles[self.active_console]
+            .console
+            .set_fg(
+                x.try_into().ok().expect("Must be i32 convertible"),
+                y.try_into().ok().expect("Must be i32 convertible"),
+                fg.into(),
+            );
+    }
+
     /// Draws a filled box, with single line characters.
     pub fn draw_box<COLOR, COLOR2, X, Y, W, H>(
         &mut self,
@@ -691,6 +1141,24 @@ impl BTerm {
             );
     }
 
+    /// Draws a grid of cells inside `bounds`: a hollow single-line outer box
+    /// with `cols` by `rows` internal divisions, using CP437 line glyphs with
+    /// the correct junction characters. Print your cell content afterwards.
+    pub fn draw_grid(&mut self, bounds: Rect, cols: i32, rows: i32, color: ColorPair) {
+        let mut bi = BACKEND_INTERNAL.lock();
+        crate::prelude::draw_grid(
+            bi.consoles[self.active_console].console.as_mut(),
+            bounds.x1,
+            bounds.y1,
+            bounds.width(),
+            bounds.height(),
+            cols,
+            rows,
+            color.fg,
+            color.bg,
+        );
+    }
+
     /// Draws a horizontal bar, suitable for health-bars or progress bars.
     #[allow(clippy::too_many_arguments)]
     pub fn draw_bar_horizontal<COLOR, COLOR2, X, Y, W, N, MAX>(
@@ -724,6 +1192,59 @@ impl BTerm {
             );
     }
 
+    /// Draws a horizontal bar with a label centered over it. The label text
+    /// swaps foreground/background per-cell, so it remains readable over both
+    /// the filled and empty portions of the bar.
+    #[allow(clippy::too_many_arguments)]
+    pub fn draw_bar_horizontal_labeled<COLOR, COLOR2, X, Y, W, N, MAX>(
+        &mut self,
+        x: X,
+        y: Y,
+        width: W,
+        n: N,
+        max: MAX,
+        label: &str,
+        fg: COLOR,
+        bg: COLOR2,
+    ) where
+        COLOR: Into<RGBA>,
+        COLOR2: Into<RGBA>,
+        X: TryInto<i32>,
+        Y: TryInto<i32>,
+        W: TryInto<i32>,
+        N: TryInto<i32>,
+        MAX: TryInto<i32>,
+    {
+        let x = x.try_into().ok().expect("Must be i32 convertible");
+        let y = y.try_into().ok().expect("Must be i32 convertible");
+        let width = width.try_into().ok().expect("Must be i32 convertible");
+        let n = n.try_into().ok().expect("Must be i32 convertible");
+        let max = max.try_into().ok().expect("Must be i32 convertible");
+        let fg = fg.into();
+        let bg = bg.into();
+
+        let mut lock = BACKEND_INTERNAL.lock();
+        let console = &mut lock.consoles[self.active_console].console;
+        console.draw_bar_horizontal(x, y, width, n, max, fg, bg);
+
+        // Same fill math as the bar itself, so the color swap matches the
+        // filled/empty boundary exactly.
+        let percent = n as f32 / max as f32;
+        let fill_width = (percent * width as f32) as i32;
+        let label_start = x + (width / 2) - (label.chars().count() as i32 / 2);
+        for (i, c) in label.chars().enumerate() {
+            let cx = label_start + i as i32;
+            if cx < x || cx >= x + width {
+                continue;
+            }
+            if cx - x <= fill_width {
+                console.set(cx, y, bg, fg, crate::prelude::to_cp437(c));
+            } else {
+                console.set(cx, y, fg, bg, crate::prelude::to_cp437(c));
+            }
+        }
+    }
+
     /// Draws a vertical bar, suitable for health-bars or progress bars.
     #[allow(clippy::too_many_arguments)]
     pub fn draw_bar_vertical<COLOR, COLOR2, X, Y, H, N, MAX>(
@@ -1025,6 +1546,24 @@ impl BTerm {
             .set_char_size(width, height);
     }
 
+    #[cfg(all(
+        any(feature = "opengl", feature = "webgpu"),
+        not(target_arch = "wasm32")
+    ))]
+    /// Resizes the character grid of the specified console layer at runtime,
+    /// rebuilding its backing storage, and fires `BEvent::ConsoleResized`.
+    /// Useful for growing or shrinking the play area when UI panels open or
+    /// close.
+    pub fn set_console_char_size(&mut self, console: usize, width: u32, height: u32) {
+        BACKEND_INTERNAL.lock().consoles[console]
+            .console
+            .set_char_size(width, height);
+        INPUT.lock().push_event(BEvent::ConsoleResized {
+            console,
+            new_size: Point::new(width as i32, height as i32),
+        });
+    }
+
     #[cfg(all(
         any(feature = "opengl", feature = "webgpu"),
         not(target_arch = "wasm32")
@@ -1041,6 +1580,21 @@ impl BTerm {
         //panic!("This will be supported when `winit` stops crashing on resize request.");
     }
 
+    /// Returns the DPI-adjusted scale factor in use by the screen scaler when
+    /// computing backing buffer output coordinates. Useful for translating
+    /// your own overlay coordinates.
+    #[cfg(any(feature = "opengl", feature = "webgpu"))]
+    pub fn scale_factor(&self) -> f32 {
+        crate::hal::BACKEND.lock().screen_scaler.scale_factor()
+    }
+
+    /// Returns the DPI-adjusted scale factor. Always 1.0 on back-ends that
+    /// don't scale their output.
+    #[cfg(not(any(feature = "opengl", feature = "webgpu")))]
+    pub fn scale_factor(&self) -> f32 {
+        1.0
+    }
+
     /// Take a screenshot - Native only
     #[cfg(all(
         any(feature = "opengl", feature = "webgpu"),
@@ -1059,6 +1613,137 @@ impl BTerm {
         // Do nothing
     }
 
+    /// Rasterizes a console's glyphs into an RGBA image using its font
+    /// texture, and saves the result as a PNG at `path`. Unlike `screenshot`,
+    /// this works entirely on the CPU from the console's cell buffer, so it
+    /// doesn't depend on the live framebuffer and works for any console -
+    /// not just the active one. Cells are rendered at the font's native tile
+    /// size; glyph pixels are tinted with the cell foreground, and
+    /// transparent font pixels take the cell background.
+    #[cfg(all(
+        any(feature = "opengl", feature = "webgpu"),
+        not(target_arch = "wasm32")
+    ))]
+    pub fn export_console_png(&self, console: usize, path: &str) -> BResult<()> {
+        let bi = BACKEND_INTERNAL.lock();
+        let font = &bi.fonts[bi.consoles[console].font_index];
+        let layer = bi.consoles[console].console.to_xp_layer();
+
+        let font_img = Font::load_image(&font.bitmap_file);
+        let mut font_img = font_img.to_rgba8();
+        // Same background keying the renderer applies when uploading the
+        // font texture.
+        if let Some(bg_rgb) = font.explicit_background {
+            let key = [
+                (bg_rgb.r * 255.0) as u8,
+                (bg_rgb.g * 255.0) as u8,
+                (bg_rgb.b * 255.0) as u8,
+            ];
+            for pixel in font_img.pixels_mut() {
+                if pixel.0[0..3] == key {
+                    pixel.0 = [0, 0, 0, 0];
+                }
+            }
+        }
+
+        let (tile_w, tile_h) = font.tile_size;
+        let glyphs_per_row = font_img.width() / tile_w;
+        let mut out = image::RgbaImage::new(
+            layer.width as u32 * tile_w,
+            layer.height as u32 * tile_h,
+        );
+        for y in 0..layer.height {
+            for x in 0..layer.width {
+                let cell = layer.get(x, y).unwrap();
+                let gx = (cell.ch % glyphs_per_row) * tile_w;
+                let gy = (cell.ch / glyphs_per_row) * tile_h;
+                for py in 0..tile_h {
+                    for px in 0..tile_w {
+                        let texel = font_img.get_pixel(gx + px, gy + py).0;
+                        let shaded = if texel[3] == 0 {
+                            [cell.bg.r, cell.bg.g, cell.bg.b, 255]
+                        } else {
+                            [
+                                (u16::from(texel[0]) * u16::from(cell.fg.r) / 255) as u8,
+                                (u16::from(texel[1]) * u16::from(cell.fg.g) / 255) as u8,
+                                (u16::from(texel[2]) * u16::from(cell.fg.b) / 255) as u8,
+                                255,
+                            ]
+                        };
+                        out.put_pixel(
+                            x as u32 * tile_w + px,
+                            y as u32 * tile_h + py,
+                            image::Rgba(shaded),
+                        );
+                    }
+                }
+            }
+        }
+        out.save(path)?;
+        Ok(())
+    }
+
+    /// Opts in (or out) of low-power redraw-on-input mode. When enabled, the
+    /// main loop waits for events instead of polling, and only redraws when
+    /// there's input, a pending resize, or an explicit `request_redraw` call.
+    /// Ideal for turn-based games; real-time games should leave it off and
+    /// keep polling. Native OpenGL only.
+    #[cfg(all(feature = "opengl", not(target_arch = "wasm32")))]
+    pub fn set_redraw_on_input(&mut self, enabled: bool) {
+        BACKEND.lock().redraw_on_input = enabled;
+    }
+
+    /// Requests a redraw, waking the main loop if it is idle in
+    /// redraw-on-input mode. Call this after changing state outside the
+    /// input path (timers, network messages). Native OpenGL only.
+    #[cfg(all(feature = "opengl", not(target_arch = "wasm32")))]
+    pub fn request_redraw(&mut self) {
+        BACKEND.lock().redraw_requested = true;
+    }
+
+    /// Enables or disables IME (input method editor) composition for the
+    /// window, e.g. when a text field gains or loses focus. While enabled,
+    /// composition progress arrives as `BEvent::ImePreedit` and finished text
+    /// as `BEvent::ImeCommit`; plain Latin input still arrives as
+    /// `BEvent::Character` when IME is off. Native OpenGL only.
+    #[cfg(all(feature = "opengl", not(target_arch = "wasm32")))]
+    pub fn set_ime_enabled(&mut self, enabled: bool) {
+        BACKEND.lock().ime_request = Some(enabled);
+    }
+
+    /// Compiles a user-supplied GLSL shader and installs it as the final
+    /// backing-buffer composite pass, replacing the built-in composite and
+    /// scanline shaders - use it for CRT curvature, bloom and similar
+    /// whole-screen effects. The shader samples the backing buffer the same
+    /// way the built-ins do (see `hal/shader_strings.rs` for their source and
+    /// the `screenSize` uniform supplied each frame). On a compile or link
+    /// error the error text is returned and the built-in pass stays active.
+    /// Native OpenGL only.
+    #[cfg(all(feature = "opengl", not(target_arch = "wasm32")))]
+    pub fn set_post_shader(&mut self, vertex_src: &str, fragment_src: &str) -> BResult<()> {
+        let mut be = BACKEND.lock();
+        let shader = {
+            let gl = be.gl.as_ref().ok_or("No OpenGL context")?;
+            Shader::try_new(gl, vertex_src, fragment_src)?
+        };
+        let mut bi = BACKEND_INTERNAL.lock();
+        let index = bi.shaders.len();
+        bi.shaders.push(shader);
+        be.custom_post_shader = Some(index);
+        Ok(())
+    }
+
+    /// Sets the window's minimum and maximum inner size, in logical pixels.
+    /// `None` for either bound removes that constraint. The change is queued
+    /// and applied by the main loop on the next frame. Native OpenGL only.
+    #[cfg(all(feature = "opengl", not(target_arch = "wasm32")))]
+    pub fn set_size_constraints(&mut self, min: Option<Point>, max: Option<Point>) {
+        BACKEND.lock().size_constraint_request = Some((
+            min.map(|p| (p.x as u32, p.y as u32)),
+            max.map(|p| (p.x as u32, p.y as u32)),
+        ));
+    }
+
     /// Register a sprite sheet (OpenGL - native or WASM - only)
     #[cfg(any(feature = "opengl", feature = "webgpu"))]
     pub fn register_spritesheet(&mut self, ss: SpriteSheet) -> usize {
@@ -0,0 +1,37 @@
+use crate::prelude::FontCharType;
+
+/// A multi-frame glyph animation: a list of frames and how long each frame
+/// is displayed. Combine with `BTerm::set_animated` to render blinking or
+/// animated tiles without tracking frame indices in game code.
+#[derive(Clone, Debug, PartialEq)]
+pub struct AnimatedGlyph {
+    /// The glyphs to cycle through, in order.
+    pub frames: Vec<FontCharType>,
+    /// How long each frame is displayed, in seconds.
+    pub frame_duration_seconds: f32,
+}
+
+impl AnimatedGlyph {
+    /// Creates an animation from a list of frames and a per-frame duration
+    /// in seconds.
+    pub fn new(frames: Vec<FontCharType>, frame_duration_seconds: f32) -> Self {
+        Self {
+            frames,
+            frame_duration_seconds,
+        }
+    }
+
+    /// Selects the frame to display at the given running time, looping
+    /// through the frame list. Returns glyph 0 if the animation has no
+    /// frames; a non-positive frame duration always shows the first frame.
+    pub fn frame_at(&self, run_time_seconds: f32) -> FontCharType {
+        if self.frames.is_empty() {
+            return 0;
+        }
+        if self.frame_duration_seconds <= 0.0 {
+            return self.frames[0];
+        }
+        let frame = (run_time_seconds / self.frame_duration_seconds) as usize;
+        self.frames[frame % self.frames.len()]
+    }
+}
@@ -5,29 +5,90 @@
 use crate::prelude::{BTerm, FontCharType, TextAlign};
 use crate::BResult;
 use bracket_color::prelude::{ColorPair, RGBA};
-use bracket_geometry::prelude::{Point, PointF, Radians, Rect};
+use bracket_geometry::prelude::{BresenhamInclusive, Point, PointF, Radians, Rect};
 use object_pool::{Pool, Reusable};
 use parking_lot::Mutex;
 use std::convert::TryInto;
+use std::sync::atomic::{AtomicUsize, Ordering};
 use std::sync::Arc;
 
+// Capacities used when the lazy statics below first initialize. Defaults
+// match the historical hardcoded values; `configure_command_buffers` can
+// change them before first use.
+static POOL_SIZE: AtomicUsize = AtomicUsize::new(128);
+static BATCH_CAPACITY: AtomicUsize = AtomicUsize::new(5000);
+static BUFFER_CAPACITY: AtomicUsize = AtomicUsize::new(100);
+
+/// Overrides the sizes of the global draw-batch pool and command buffers:
+/// `pool_size` is the number of pooled `DrawBatch`es (the maximum alive at
+/// once), `batch_capacity` the command capacity pre-allocated per batch, and
+/// `buffer_capacity` the submission queue's initial capacity. Heavy-UI apps
+/// can size up and tiny apps size down without recompiling the crate.
+///
+/// The pools are created lazily, so this must be called before the first
+/// `DrawBatch::new` or batch submission; calls after that point have no
+/// effect.
+pub fn configure_command_buffers(
+    pool_size: usize,
+    batch_capacity: usize,
+    buffer_capacity: usize,
+) {
+    POOL_SIZE.store(pool_size, Ordering::Relaxed);
+    BATCH_CAPACITY.store(batch_capacity, Ordering::Relaxed);
+    BUFFER_CAPACITY.store(buffer_capacity, Ordering::Relaxed);
+}
+
+lazy_static! {
+    // Submission side of the double-buffered command queue. Submitting
+    // systems only hold this lock long enough to push a batch; rendering
+    // swaps it with RENDER_BUFFER and works on the detached copy.
+    static ref COMMAND_BUFFER: Mutex<Vec<(usize, Vec<(u32, DrawCommand)>)>> =
+        Mutex::new(Vec::with_capacity(BUFFER_CAPACITY.load(Ordering::Relaxed)));
+}
+
 lazy_static! {
-    static ref COMMAND_BUFFER: Mutex<Vec<(usize, Vec<DrawCommand>)>> =
-        Mutex::new(Vec::with_capacity(100));
+    // Render side of the double-buffered command queue. Empty between
+    // frames; holds the previous frame's submissions while they are drawn,
+    // without blocking new submissions.
+    static ref RENDER_BUFFER: Mutex<Vec<(usize, Vec<(u32, DrawCommand)>)>> =
+        Mutex::new(Vec::with_capacity(BUFFER_CAPACITY.load(Ordering::Relaxed)));
 }
 
 lazy_static! {
-    static ref BUFFER_POOL: Arc<Pool<DrawBatch>> = Arc::new(Pool::new(128, || DrawBatch {
-        batch: Vec::with_capacity(5000),
-        z_count: 0,
-        needs_sort: false
-    }));
+    // Command vectors whose contents have been rendered. They are handed back
+    // to `submit` so a steady-state game loop stops allocating entirely.
+    static ref RETIRED_BUFFERS: Mutex<Vec<Vec<(u32, DrawCommand)>>> =
+        Mutex::new(Vec::with_capacity(BUFFER_CAPACITY.load(Ordering::Relaxed)));
+}
+
+/// Maximum number of flushed command vectors retained for reuse.
+const MAX_RETIRED_BUFFERS: usize = 128;
+
+fn retire_buffers(buffer: &mut Vec<(usize, Vec<(u32, DrawCommand)>)>) {
+    let mut retired = RETIRED_BUFFERS.lock();
+    for (_, mut batch) in buffer.drain(..) {
+        if retired.len() < MAX_RETIRED_BUFFERS {
+            batch.clear();
+            retired.push(batch);
+        }
+    }
+}
+
+lazy_static! {
+    static ref BUFFER_POOL: Arc<Pool<DrawBatch>> = Arc::new(Pool::new(
+        POOL_SIZE.load(Ordering::Relaxed),
+        || DrawBatch {
+            batch: Vec::with_capacity(BATCH_CAPACITY.load(Ordering::Relaxed)),
+            z_count: 0,
+            needs_sort: false
+        }
+    ));
 }
 
 /// Clears the global command buffer. This is called internally by BTerm at the end of each
 /// frame. You really shouldn't need to call this yourself.
 pub fn clear_command_buffer() -> BResult<()> {
-    COMMAND_BUFFER.lock().clear();
+    retire_buffers(&mut COMMAND_BUFFER.lock());
     Ok(())
 }
 
@@ -51,6 +112,10 @@ pub enum DrawCommand {
         pos: Point,
         bg: RGBA,
     },
+    SetForeground {
+        pos: Point,
+        fg: RGBA,
+    },
     Print {
         pos: Point,
         text: String,
@@ -64,6 +129,11 @@ pub enum DrawCommand {
         pos: Point,
         text: String,
     },
+    PrintVertical {
+        pos: Point,
+        text: String,
+        color: ColorPair,
+    },
     PrintColorRight {
         pos: Point,
         text: String,
@@ -114,6 +184,27 @@ pub enum DrawCommand {
         color: ColorPair,
         glyph: FontCharType,
     },
+    FillRegionGradient {
+        bounds: Rect,
+        from: RGBA,
+        to: RGBA,
+        glyph: FontCharType,
+        horizontal: bool,
+    },
+    Triangle {
+        a: Point,
+        b: Point,
+        c: Point,
+        color: ColorPair,
+        glyph: FontCharType,
+    },
+    FillTriangle {
+        a: Point,
+        b: Point,
+        c: Point,
+        color: ColorPair,
+        glyph: FontCharType,
+    },
     BarHorizontal {
         pos: Point,
         width: i32,
@@ -121,6 +212,14 @@ pub enum DrawCommand {
         max: i32,
         color: ColorPair,
     },
+    BarHorizontalLabeled {
+        pos: Point,
+        width: i32,
+        n: i32,
+        max: i32,
+        label: String,
+        color: ColorPair,
+    },
     BarVertical {
         pos: Point,
         height: i32,
@@ -131,6 +230,10 @@ pub enum DrawCommand {
     SetClipping {
         clip: Option<Rect>,
     },
+    PushClip {
+        clip: Rect,
+    },
+    PopClip,
     SetFgAlpha {
         alpha: f32,
     },
@@ -174,16 +277,42 @@ impl DrawBatch {
     }
 
     /// Submits a batch to the global drawing buffer, and empties the batch.
+    /// The batch's command vector is moved into the buffer and replaced with
+    /// a previously-retired allocation, so no per-frame allocation occurs
+    /// once the pool has warmed up.
     pub fn submit(&mut self, z_order: usize) -> BResult<()> {
         if self.needs_sort {
             self.batch.sort_by(|a, b| a.0.cmp(&b.0));
         }
-        let mut new_batch = Vec::with_capacity(self.batch.len());
-        self.batch.drain(0..).for_each(|(_, b)| new_batch.push(b));
-        COMMAND_BUFFER.lock().push((z_order, new_batch));
+        let mut commands = RETIRED_BUFFERS.lock().pop().unwrap_or_default();
+        std::mem::swap(&mut self.batch, &mut commands);
+        COMMAND_BUFFER.lock().push((z_order, commands));
         Ok(())
     }
 
+    /// Clones the batch's current commands, in insertion order and without
+    /// their internal z tags. Use this to cache a built batch (a static HUD
+    /// frame, for example) and replay it on later frames with
+    /// `extend_from_snapshot` instead of rebuilding it every tick.
+    pub fn snapshot(&self) -> Vec<DrawCommand> {
+        let mut tagged: Vec<&(u32, DrawCommand)> = self.batch.iter().collect();
+        if self.needs_sort {
+            tagged.sort_by(|a, b| a.0.cmp(&b.0));
+        }
+        tagged.iter().map(|(_, cmd)| cmd.clone()).collect()
+    }
+
+    /// Appends previously-snapshotted commands to the batch, preserving
+    /// their order relative to each other and to any commands already
+    /// queued.
+    pub fn extend_from_snapshot(&mut self, cmds: &[DrawCommand]) -> &mut Self {
+        for cmd in cmds {
+            let z = self.next_z();
+            self.batch.push((z, cmd.clone()));
+        }
+        self
+    }
+
     /// Adds a CLS (clear screen) to the drawing batch
     pub fn cls(&mut self) -> &mut Self {
         let z = self.next_z();
@@ -252,7 +381,9 @@ impl DrawBatch {
         self
     }
 
-    /// Pushes a fancy terminal character
+    /// Pushes a fancy terminal character. Negative scale components mirror
+    /// the glyph around its center: `scale.x = -1.0` flips horizontally,
+    /// `scale.y = -1.0` vertically.
     pub fn set_fancy<ANGLE: Into<Radians>, Z: TryInto<i32>, G: TryInto<FontCharType>>(
         &mut self,
         position: PointF,
@@ -277,6 +408,28 @@ impl DrawBatch {
         self
     }
 
+    /// Pushes a fancy terminal character with explicit mirroring: flips the
+    /// glyph horizontally and/or vertically around its center by negating
+    /// the matching scale component.
+    #[allow(clippy::too_many_arguments)]
+    pub fn set_fancy_mirrored<ANGLE: Into<Radians>, Z: TryInto<i32>, G: TryInto<FontCharType>>(
+        &mut self,
+        position: PointF,
+        z_order: Z,
+        rotation: ANGLE,
+        scale: PointF,
+        mirror_horizontal: bool,
+        mirror_vertical: bool,
+        color: ColorPair,
+        glyph: G,
+    ) -> &mut Self {
+        let scale = PointF {
+            x: if mirror_horizontal { -scale.x } else { scale.x },
+            y: if mirror_vertical { -scale.y } else { scale.y },
+        };
+        self.set_fancy(position, z_order, rotation, scale, color, glyph)
+    }
+
     /// Sets an individual cell glyph
     pub fn set_bg<COLOR>(&mut self, pos: Point, bg: COLOR) -> &mut Self
     where
@@ -299,6 +452,18 @@ impl DrawBatch {
         self
     }
 
+    /// Sets an individual cell's foreground color, leaving the existing glyph
+    /// and background untouched
+    pub fn set_fg<COLOR>(&mut self, pos: Point, fg: COLOR) -> &mut Self
+    where
+        COLOR: Into<RGBA>,
+    {
+        let z = self.next_z();
+        self.batch
+            .push((z, DrawCommand::SetForeground { pos, fg: fg.into() }));
+        self
+    }
+
     /// Prints formatted text, using the doryen_rs convention. For example:
     /// "#[blue]This blue text contains a #[pink]pink#[] word"
     pub fn printer<S: ToString>(
@@ -545,6 +710,26 @@ impl DrawBatch {
         self
     }
 
+    /// Prints text vertically, one glyph per row downwards from `pos`. Text
+    /// that would extend past the bottom of the console is clipped.
+    pub fn print_vertical<S: ToString>(
+        &mut self,
+        pos: Point,
+        text: S,
+        color: ColorPair,
+    ) -> &mut Self {
+        let z = self.next_z();
+        self.batch.push((
+            z,
+            DrawCommand::PrintVertical {
+                pos,
+                text: text.to_string(),
+                color,
+            },
+        ));
+        self
+    }
+
     /// Prints right aligned text
     pub fn print_right<S: ToString>(&mut self, pos: Point, text: S) -> &mut Self {
         let z = self.next_z();
@@ -712,6 +897,82 @@ impl DrawBatch {
         self
     }
 
+    /// Fills a region with a glyph, interpolating the background color from
+    /// `from` to `to` across the rect with `RGBA::lerp` - left to right when
+    /// `horizontal` is true, top to bottom otherwise. The glyph is drawn in
+    /// white; use a space to show only the gradient.
+    pub fn fill_region_gradient(
+        &mut self,
+        bounds: Rect,
+        from: RGBA,
+        to: RGBA,
+        glyph: FontCharType,
+        horizontal: bool,
+    ) -> &mut Self {
+        let z = self.next_z();
+        self.batch.push((
+            z,
+            DrawCommand::FillRegionGradient {
+                bounds,
+                from,
+                to,
+                glyph,
+                horizontal,
+            },
+        ));
+        self
+    }
+
+    /// Draws the outline of a triangle between three points, tracing each
+    /// edge with a Bresenham line. Collinear points degenerate to a single
+    /// line.
+    pub fn draw_triangle<G: TryInto<FontCharType>>(
+        &mut self,
+        a: Point,
+        b: Point,
+        c: Point,
+        color: ColorPair,
+        glyph: G,
+    ) -> &mut Self {
+        let z = self.next_z();
+        self.batch.push((
+            z,
+            DrawCommand::Triangle {
+                a,
+                b,
+                c,
+                color,
+                glyph: glyph.try_into().ok().expect("Must be u16 convertible"),
+            },
+        ));
+        self
+    }
+
+    /// Draws a filled triangle between three points, using a scanline fill
+    /// bounded by the Bresenham-rasterized edges. Collinear points degenerate
+    /// to a single line.
+    pub fn fill_triangle<G: TryInto<FontCharType>>(
+        &mut self,
+        a: Point,
+        b: Point,
+        c: Point,
+        color: ColorPair,
+        glyph: G,
+    ) -> &mut Self {
+        let z = self.next_z();
+        self.batch.push((
+            z,
+            DrawCommand::FillTriangle {
+                a,
+                b,
+                c,
+                color,
+                glyph: glyph.try_into().ok().expect("Must be u16 convertible"),
+            },
+        ));
+        self
+    }
+
     /// Draw a horizontal progress bar
     pub fn bar_horizontal<W, N, MAX>(
         &mut self,
@@ -740,6 +1001,39 @@ impl DrawBatch {
         self
     }
 
+    /// Adds a labeled horizontal progress bar to the batch: the bar is drawn
+    /// first, then the label centered over it with per-cell contrasting
+    /// colors.
+    pub fn bar_horizontal_labeled<W, N, MAX, S>(
+        &mut self,
+        pos: Point,
+        width: W,
+        n: N,
+        max: MAX,
+        label: S,
+        color: ColorPair,
+    ) -> &mut Self
+    where
+        W: TryInto<i32>,
+        N: TryInto<i32>,
+        MAX: TryInto<i32>,
+        S: ToString,
+    {
+        let z = self.next_z();
+        self.batch.push((
+            z,
+            DrawCommand::BarHorizontalLabeled {
+                pos,
+                width: width.try_into().ok().expect("Must be i32 convertible"),
+                n: n.try_into().ok().expect("Must be i32 convertible"),
+                max: max.try_into().ok().expect("Must be i32 convertible"),
+                label: label.to_string(),
+                color,
+            },
+        ));
+        self
+    }
+
     /// Draw a horizontal progress bar
     pub fn bar_horizontal_with_z<W, N, MAX>(
         &mut self,
@@ -833,6 +1127,23 @@ impl DrawBatch {
         self
     }
 
+    /// Pushes a clipping rectangle onto the clip stack, making it active. Use
+    /// with `pop_clip` to restore the parent's clipping region when drawing
+    /// nested UI elements.
+    pub fn push_clip(&mut self, clip: Rect) -> &mut Self {
+        let z = self.next_z();
+        self.batch.push((z, DrawCommand::PushClip { clip }));
+        self
+    }
+
+    /// Pops the most recent clipping rectangle from the clip stack, restoring
+    /// the one below it. Popping with an empty stack clears clipping entirely.
+    pub fn pop_clip(&mut self) -> &mut Self {
+        let z = self.next_z();
+        self.batch.push((z, DrawCommand::PopClip));
+        self
+    }
+
     /// Apply an alpha channel value to all cells' foregrounds in the current terminal.
     pub fn set_all_fg_alpha(&mut self, alpha: f32) -> &mut Self {
         let z = self.next_z();
@@ -855,12 +1166,23 @@ impl DrawBatch {
     }
 }
 
+/// Orders detached submissions by z_order. The sort must be stable: batches
+/// submitted at the same z_order keep their submission order, so overlapping
+/// same-z draws render identically every frame instead of flickering.
+fn sort_by_z_order(buffer: &mut [(usize, Vec<(u32, DrawCommand)>)]) {
+    buffer.sort_by(|a, b| a.0.cmp(&b.0));
+}
+
 /// Submits the current batch to the BTerm buffer and empties it
 pub fn render_draw_buffer(bterm: &mut BTerm) -> BResult<()> {
-    let mut buffer = COMMAND_BUFFER.lock();
-    buffer.sort_unstable_by(|a, b| a.0.cmp(&b.0));
+    // Swap the submission buffer for the (empty) render buffer, so drawing
+    // the frame doesn't serialize against systems submitting new batches.
+    let mut buffer = RENDER_BUFFER.lock();
+    std::mem::swap(&mut *COMMAND_BUFFER.lock(), &mut *buffer);
+    sort_by_z_order(&mut buffer);
+    let mut clip_stack: Vec<Rect> = Vec::new();
     buffer.iter().for_each(|(_, batch)| {
-        batch.iter().for_each(|cmd| match cmd {
+        batch.iter().for_each(|(_, cmd)| match cmd {
             DrawCommand::ClearScreen => bterm.cls(),
             DrawCommand::ClearToColor { color } => bterm.cls_bg(*color),
             DrawCommand::SetTarget { console } => bterm.set_active_console(*console),
@@ -868,6 +1190,7 @@ pub fn render_draw_buffer(bterm: &mut BTerm) -> BResult<()> {
                 bterm.set(pos.x, pos.y, color.fg, color.bg, *glyph)
             }
             DrawCommand::SetBackground { pos, bg } => bterm.set_bg(pos.x, pos.y, *bg),
+            DrawCommand::SetForeground { pos, fg } => bterm.set_fg(pos.x, pos.y, *fg),
             DrawCommand::Print { pos, text } => bterm.print(pos.x, pos.y, &text),
             DrawCommand::PrintColor { pos, text, color } => {
                 bterm.print_color(pos.x, pos.y, color.fg, color.bg, &text)
@@ -883,6 +1206,16 @@ pub fn render_draw_buffer(bterm: &mut BTerm) -> BResult<()> {
                 bterm.print_color_centered_at(pos.x, pos.y, color.fg, color.bg, &text)
             }
             DrawCommand::PrintRight { pos, text } => bterm.print_right(pos.x, pos.y, text),
+            DrawCommand::PrintVertical { pos, text, color } => {
+                let height = bterm.get_char_size().1 as i32;
+                for (i, glyph) in text.chars().enumerate() {
+                    let y = pos.y + i as i32;
+                    if y >= height {
+                        break;
+                    }
+                    bterm.set(pos.x, y, color.fg, color.bg, crate::prelude::to_cp437(glyph));
+                }
+            }
             DrawCommand::PrintColorRight { pos, text, color } => {
                 bterm.print_color_right(pos.x, pos.y, color.fg, color.bg, text)
             }
@@ -927,6 +1260,70 @@ pub fn render_draw_buffer(bterm: &mut BTerm) -> BResult<()> {
             DrawCommand::FillRegion { pos, color, glyph } => {
                 bterm.fill_region::<RGBA, RGBA, FontCharType>(*pos, *glyph, color.fg, color.bg)
             }
+            DrawCommand::FillRegionGradient {
+                bounds,
+                from,
+                to,
+                glyph,
+                horizontal,
+            } => {
+                let span = if *horizontal {
+                    bounds.width() - 1
+                } else {
+                    bounds.height() - 1
+                }
+                .max(1) as f32;
+                let white = RGBA::from_f32(1.0, 1.0, 1.0, 1.0);
+                bounds.for_each(|point| {
+                    let distance = if *horizontal {
+                        point.x - bounds.x1
+                    } else {
+                        point.y - bounds.y1
+                    } as f32;
+                    let bg = from.lerp(*to, distance / span);
+                    bterm.set(point.x, point.y, white, bg, *glyph);
+                });
+            }
+            DrawCommand::Triangle {
+                a,
+                b,
+                c,
+                color,
+                glyph,
+            } => {
+                for (start, end) in [(a, b), (b, c), (c, a)] {
+                    for point in BresenhamInclusive::new(*start, *end) {
+                        bterm.set(point.x, point.y, color.fg, color.bg, *glyph);
+                    }
+                }
+            }
+            DrawCommand::FillTriangle {
+                a,
+                b,
+                c,
+                color,
+                glyph,
+            } => {
+                // Rasterize the edges, tracking the leftmost/rightmost cell
+                // per row, then fill each row's span. Collinear triangles
+                // produce one-cell spans - just the line.
+                let min_y = a.y.min(b.y).min(c.y);
+                let max_y = a.y.max(b.y).max(c.y);
+                let mut spans = vec![(i32::MAX, i32::MIN); (max_y - min_y + 1) as usize];
+                for (start, end) in [(a, b), (b, c), (c, a)] {
+                    for point in BresenhamInclusive::new(*start, *end) {
+                        let span = &mut spans[(point.y - min_y) as usize];
+                        span.0 = span.0.min(point.x);
+                        span.1 = span.1.max(point.x);
+                    }
+                }
+                for (row, (x1, x2)) in spans.iter().enumerate() {
+                    let y = min_y + row as i32;
+                    for x in *x1..=*x2 {
+                        bterm.set(x, y, color.fg, color.bg, *glyph);
+                    }
+                }
+            }
             DrawCommand::BarHorizontal {
                 pos,
                 width,
@@ -934,6 +1331,15 @@ pub fn render_draw_buffer(bterm: &mut BTerm) -> BResult<()> {
                 max,
                 color,
             } => bterm.draw_bar_horizontal(pos.x, pos.y, *width, *n, *max, color.fg, color.bg),
+            DrawCommand::BarHorizontalLabeled {
+                pos,
+                width,
+                n,
+                max,
+                label,
+                color,
+            } => bterm
+                .draw_bar_horizontal_labeled(pos.x, pos.y, *width, *n, *max, label, color.fg, color.bg),
             DrawCommand::BarVertical {
                 pos,
                 height,
@@ -942,6 +1348,14 @@ pub fn render_draw_buffer(bterm: &mut BTerm) -> BResult<()> {
                 color,
             } => bterm.draw_bar_vertical(pos.x, pos.y, *height, *n, *max, color.fg, color.bg),
             DrawCommand::SetClipping { clip } => bterm.set_clipping(*clip),
+            DrawCommand::PushClip { clip } => {
+                clip_stack.push(*clip);
+                bterm.set_clipping(Some(*clip));
+            }
+            DrawCommand::PopClip => {
+                clip_stack.pop();
+                bterm.set_clipping(clip_stack.last().copied());
+            }
             DrawCommand::SetFgAlpha { alpha } => bterm.set_all_fg_alpha(*alpha),
             DrawCommand::SetBgAlpha { alpha } => bterm.set_all_fg_alpha(*alpha),
             DrawCommand::SetAllAlpha { fg, bg } => bterm.set_all_alpha(*fg, *bg),
@@ -959,6 +1373,49 @@ pub fn render_draw_buffer(bterm: &mut BTerm) -> BResult<()> {
             }
         })
     });
-    buffer.clear();
+    retire_buffers(&mut buffer);
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    // Batches at the same z_order must keep their submission order after the
+    // render sort; an unstable sort could swap them between frames.
+    fn equal_z_batches_keep_submission_order() {
+        let mut buffer = vec![
+            (
+                5,
+                vec![(
+                    0u32,
+                    DrawCommand::Print {
+                        pos: Point::zero(),
+                        text: "first".to_string(),
+                    },
+                )],
+            ),
+            (
+                5,
+                vec![(
+                    0u32,
+                    DrawCommand::Print {
+                        pos: Point::zero(),
+                        text: "second".to_string(),
+                    },
+                )],
+            ),
+            (1, vec![(0u32, DrawCommand::ClearScreen)]),
+        ];
+        sort_by_z_order(&mut buffer);
+        assert_eq!(buffer[0].0, 1);
+        for (expected, (z, batch)) in ["first", "second"].iter().zip(buffer.iter().skip(1)) {
+            assert_eq!(*z, 5);
+            match &batch[0].1 {
+                DrawCommand::Print { text, .. } => assert_eq!(text, expected),
+                _ => panic!("Expected a Print command"),
+            }
+        }
+    }
+}
@@ -59,6 +59,9 @@ pub trait Console {
     /// Sets a single cell's background color.
     fn set_bg(&mut self, x: i32, y: i32, bg: RGBA);
 
+    /// Sets a single cell's foreground color, leaving the glyph and background untouched.
+    fn set_fg(&mut self, x: i32, y: i32, fg: RGBA);
+
     /// Draws a box, starting at x/y with the extents width/height using CP437 line characters
     fn draw_box(&mut self, x: i32, y: i32, width: i32, height: i32, fg: RGBA, bg: RGBA);
 
@@ -62,7 +62,9 @@ impl FlexiConsole {
         Box::new(new_console)
     }
 
-    // Insert a single tile with "fancy" attributes
+    // Insert a single tile with "fancy" attributes. Negative scale
+    // components mirror the glyph around its center via the scale sign in
+    // the fancy console shader.
     #[allow(clippy::too_many_arguments)]
     pub fn set_fancy(
         &mut self,
@@ -209,6 +211,10 @@ impl Console for FlexiConsole {
         // Does nothing for this layer type
     }
 
+    fn set_fg(&mut self, _x: i32, _y: i32, _fg: RGBA) {
+        // Does nothing for this layer type
+    }
+
     /// Draws a box, starting at x/y with the extents width/height using CP437 line characters
     fn draw_box(&mut self, sx: i32, sy: i32, width: i32, height: i32, fg: RGBA, bg: RGBA) {
         crate::prelude::draw_box(self, sx, sy, width, height, fg, bg);
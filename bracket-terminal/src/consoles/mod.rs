@@ -1,3 +1,4 @@
+mod animated_glyph;
 mod command_buffer;
 pub mod console;
 mod flexible_console;
@@ -6,8 +7,10 @@ mod sparse_console;
 mod sprite_console;
 mod sprites;
 mod text;
+mod text_field;
 mod virtual_console;
 
+pub use animated_glyph::*;
 pub use command_buffer::*;
 pub use console::*;
 pub use flexible_console::*;
@@ -16,4 +19,5 @@ pub use sparse_console::*;
 pub use sprite_console::*;
 pub use sprites::*;
 pub use text::*;
+pub use text_field::*;
 pub use virtual_console::*;
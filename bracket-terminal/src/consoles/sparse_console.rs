@@ -172,6 +172,18 @@ impl Console for SparseConsole {
         }
     }
 
+    /// Sets a single cell in the console's foreground. Cells that have never
+    /// been written have no glyph to recolor, so they are left alone.
+    fn set_fg(&mut self, x: i32, y: i32, fg: RGBA) {
+        if let Some(idx) = self.try_at(x, y) {
+            self.is_dirty = true;
+            self.tiles
+                .iter_mut()
+                .filter(|t| t.idx == idx)
+                .for_each(|t| t.fg = fg);
+        }
+    }
+
     /// Draws a box, starting at x/y with the extents width/height using CP437 line characters
     fn draw_box(&mut self, sx: i32, sy: i32, width: i32, height: i32, fg: RGBA, bg: RGBA) {
         crate::prelude::draw_box(self, sx, sy, width, height, fg, bg);
@@ -92,6 +92,10 @@ impl Console for SpriteConsole {
         // Does nothing for this layer type
     }
 
+    fn set_fg(&mut self, _x: i32, _y: i32, _fg: RGBA) {
+        // Does nothing for this layer type
+    }
+
     /// Draws a box, starting at x/y with the extents width/height using CP437 line characters
     fn draw_box(&mut self, _sx: i32, _sy: i32, _width: i32, _height: i32, _fg: RGBA, _bg: RGBA) {
         // Does nothing
@@ -14,34 +14,47 @@ fn find_color(col_name: &str) -> RGBA {
     }
 }
 
+/// Parses a doryen-style `#[color]` markup string into its component segments,
+/// without painting anything. Each entry is a run of text and the color active
+/// for that run; `None` means no `#[color]` tag was in effect (the renderer's
+/// default applies). This is the same parser `printer` uses, so it can be used
+/// for layout math such as measuring visible length without the markup.
+pub fn parse_colored_text(text: &str) -> Vec<(String, Option<RGBA>)> {
+    let mut result = Vec::new();
+    let mut color_stack = Vec::new();
+
+    for color_span in text.to_owned().split("#[") {
+        if color_span.is_empty() {
+            continue;
+        }
+        let mut col_text = color_span.splitn(2, ']');
+        let col_name = col_text.next().unwrap();
+        if let Some(text_span) = col_text.next() {
+            if !col_name.is_empty() {
+                color_stack.push(find_color(col_name));
+            } else {
+                color_stack.pop();
+            }
+            result.push((text_span.to_string(), color_stack.last().copied()));
+        }
+    }
+
+    result
+}
+
 impl ColoredTextSpans {
     pub fn new(text: &str) -> Self {
         let mut result = Self {
             length: 0,
             spans: Vec::new(),
         };
-        let mut color_stack = Vec::new();
 
-        for color_span in text.to_owned().split("#[") {
-            if color_span.is_empty() {
-                continue;
-            }
-            let mut col_text = color_span.splitn(2, ']');
-            let col_name = col_text.next().unwrap();
-            if let Some(text_span) = col_text.next() {
-                if !col_name.is_empty() {
-                    color_stack.push(find_color(col_name));
-                } else {
-                    color_stack.pop();
-                }
-                result.spans.push((
-                    *color_stack
-                        .last()
-                        .unwrap_or(&RGBA::from_u8(255, 255, 255, 255)),
-                    text_span.to_string(),
-                ));
-                result.length += text_span.chars().count();
-            }
+        for (text_span, color) in parse_colored_text(text) {
+            result.length += text_span.chars().count();
+            result.spans.push((
+                color.unwrap_or_else(|| RGBA::from_u8(255, 255, 255, 255)),
+                text_span,
+            ));
         }
 
         result
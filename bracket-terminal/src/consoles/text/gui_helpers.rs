@@ -121,6 +121,48 @@ pub fn draw_hollow_box_double(
     }
 }
 
+/// Draws a grid of cells: a hollow single-line outer box with `cols` by
+/// `rows` internal divisions, using the correct CP437 junction characters
+/// (┬ ├ ┼ ┤ ┴) where the separators meet the border and each other.
+#[allow(clippy::too_many_arguments)]
+pub fn draw_grid(
+    console: &mut dyn Console,
+    sx: i32,
+    sy: i32,
+    width: i32,
+    height: i32,
+    cols: i32,
+    rows: i32,
+    fg: RGBA,
+    bg: RGBA,
+) {
+    draw_hollow_box(console, sx, sy, width, height, fg, bg);
+
+    let col_xs: Vec<i32> = (1..cols).map(|i| sx + (i * width) / cols).collect();
+    let row_ys: Vec<i32> = (1..rows).map(|i| sy + (i * height) / rows).collect();
+
+    for x in &col_xs {
+        console.set(*x, sy, fg, bg, to_cp437('┬'));
+        console.set(*x, sy + height, fg, bg, to_cp437('┴'));
+        for y in sy + 1..sy + height {
+            console.set(*x, y, fg, bg, to_cp437('│'));
+        }
+    }
+    for y in &row_ys {
+        console.set(sx, *y, fg, bg, to_cp437('├'));
+        console.set(sx + width, *y, fg, bg, to_cp437('┤'));
+        for x in sx + 1..sx + width {
+            console.set(x, *y, fg, bg, to_cp437('─'));
+        }
+    }
+    // The horizontal separators overwrote the crossings; restore them.
+    for x in &col_xs {
+        for y in &row_ys {
+            console.set(*x, *y, fg, bg, to_cp437('┼'));
+        }
+    }
+}
+
 /// Draws a horizontal progress bar
 #[allow(clippy::too_many_arguments)]
 pub fn draw_bar_horizontal(
@@ -5,6 +5,7 @@ mod multi_tile_sprite;
 mod textblock;
 
 pub use codepage437::*;
+pub use format_string::parse_colored_text;
 pub(crate) use format_string::*;
 pub use gui_helpers::*;
 pub use multi_tile_sprite::*;
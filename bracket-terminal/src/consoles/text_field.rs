@@ -0,0 +1,203 @@
+use crate::prelude::{to_cp437, BEvent, BTerm, VirtualKeyCode};
+use bracket_color::prelude::ColorPair;
+
+/// A single-line text input field: owns the entered `String` and a cursor
+/// index. Feed it events from the input queue with `handle_event`, and draw
+/// it each frame with `render`; text longer than the field scrolls
+/// horizontally so the caret stays visible.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct TextField {
+    /// The text entered so far.
+    pub text: String,
+    /// The caret position, as a character index into `text` (0 to length
+    /// inclusive; the length position appends).
+    pub cursor: usize,
+}
+
+impl TextField {
+    /// Creates an empty text field.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a field pre-populated with `text`, with the caret at the end.
+    pub fn with_text<S: ToString>(text: S) -> Self {
+        let text = text.to_string();
+        let cursor = text.chars().count();
+        Self { text, cursor }
+    }
+
+    /// The length of the entered text, in characters.
+    pub fn len(&self) -> usize {
+        self.text.chars().count()
+    }
+
+    /// True if no text has been entered.
+    pub fn is_empty(&self) -> bool {
+        self.text.is_empty()
+    }
+
+    /// Processes a single input event: printable `Character`s and committed
+    /// IME text are inserted at the caret, backspace/delete remove around it,
+    /// and the Left/Right/Home/End keys move it. Returns true if the event
+    /// changed the field, so callers can redraw only when needed.
+    pub fn handle_event(&mut self, event: &BEvent) -> bool {
+        match event {
+            BEvent::Character { c } if !c.is_control() => {
+                self.insert_char(*c);
+                true
+            }
+            BEvent::ImeCommit { text } => {
+                for c in text.chars() {
+                    self.insert_char(c);
+                }
+                !text.is_empty()
+            }
+            BEvent::KeyboardInput {
+                key,
+                pressed: true,
+                ..
+            } => match key {
+                VirtualKeyCode::Back => {
+                    if self.cursor > 0 {
+                        self.cursor -= 1;
+                        self.remove_at_cursor();
+                        true
+                    } else {
+                        false
+                    }
+                }
+                VirtualKeyCode::Delete => {
+                    if self.cursor < self.len() {
+                        self.remove_at_cursor();
+                        true
+                    } else {
+                        false
+                    }
+                }
+                VirtualKeyCode::Left => {
+                    if self.cursor > 0 {
+                        self.cursor -= 1;
+                        true
+                    } else {
+                        false
+                    }
+                }
+                VirtualKeyCode::Right => {
+                    if self.cursor < self.len() {
+                        self.cursor += 1;
+                        true
+                    } else {
+                        false
+                    }
+                }
+                VirtualKeyCode::Home => {
+                    let moved = self.cursor != 0;
+                    self.cursor = 0;
+                    moved
+                }
+                VirtualKeyCode::End => {
+                    let end = self.len();
+                    let moved = self.cursor != end;
+                    self.cursor = end;
+                    moved
+                }
+                _ => false,
+            },
+            _ => false,
+        }
+    }
+
+    /// Draws the field as a `width`-cell row at x/y on the active console,
+    /// with a caret that blinks twice a second, drawn by swapping the colors
+    /// of the cell under it. When the text overflows the field it scrolls so
+    /// the caret stays in view, pinned to the right edge.
+    pub fn render(&self, bterm: &mut BTerm, x: i32, y: i32, width: i32, color: ColorPair) {
+        if width < 1 {
+            return;
+        }
+        let width = width as usize;
+        let offset = self.cursor.saturating_sub(width - 1);
+        let visible: Vec<char> = self.text.chars().skip(offset).take(width).collect();
+        for (i, glyph) in (0..width).map(|i| (i, visible.get(i).copied().unwrap_or(' '))) {
+            bterm.set(x + i as i32, y, color.fg, color.bg, to_cp437(glyph));
+        }
+
+        let caret_visible = ((bterm.run_time_seconds * 2.0) as i32) % 2 == 0;
+        if caret_visible {
+            let caret_column = self.cursor - offset;
+            let under = visible.get(caret_column).copied().unwrap_or(' ');
+            bterm.set(
+                x + caret_column as i32,
+                y,
+                color.bg,
+                color.fg,
+                to_cp437(under),
+            );
+        }
+    }
+
+    /// Translates the caret's character index into a byte index into `text`.
+    fn byte_index(&self, char_index: usize) -> usize {
+        self.text
+            .char_indices()
+            .nth(char_index)
+            .map_or(self.text.len(), |(i, _)| i)
+    }
+
+    fn insert_char(&mut self, c: char) {
+        let idx = self.byte_index(self.cursor);
+        self.text.insert(idx, c);
+        self.cursor += 1;
+    }
+
+    fn remove_at_cursor(&mut self) {
+        let idx = self.byte_index(self.cursor);
+        self.text.remove(idx);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn key(key: VirtualKeyCode) -> BEvent {
+        BEvent::KeyboardInput {
+            key,
+            scan_code: 0,
+            pressed: true,
+            shift: false,
+            control: false,
+            alt: false,
+        }
+    }
+
+    #[test]
+    fn typing_and_editing() {
+        let mut field = TextField::new();
+        for c in "hello".chars() {
+            assert!(field.handle_event(&BEvent::Character { c }));
+        }
+        assert_eq!(field.text, "hello");
+        assert_eq!(field.cursor, 5);
+
+        assert!(field.handle_event(&key(VirtualKeyCode::Back)));
+        assert_eq!(field.text, "hell");
+        assert!(field.handle_event(&key(VirtualKeyCode::Home)));
+        assert!(field.handle_event(&key(VirtualKeyCode::Delete)));
+        assert_eq!(field.text, "ell");
+        assert!(field.handle_event(&key(VirtualKeyCode::Right)));
+        field.handle_event(&BEvent::Character { c: 'x' });
+        assert_eq!(field.text, "exll");
+    }
+
+    #[test]
+    fn edge_keys_do_not_move_past_the_ends() {
+        let mut field = TextField::with_text("ab");
+        assert!(!field.handle_event(&key(VirtualKeyCode::Right)));
+        assert!(field.handle_event(&key(VirtualKeyCode::Home)));
+        assert!(!field.handle_event(&key(VirtualKeyCode::Left)));
+        assert!(!field.handle_event(&key(VirtualKeyCode::Back)));
+        assert_eq!(field.text, "ab");
+    }
+}
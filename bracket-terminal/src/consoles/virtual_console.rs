@@ -204,6 +204,13 @@ impl Console for VirtualConsole {
         }
     }
 
+    /// Sets a single cell in the console's foreground
+    fn set_fg(&mut self, x: i32, y: i32, fg: RGBA) {
+        if let Some(idx) = self.try_at(x, y) {
+            self.tiles[idx].fg = fg;
+        }
+    }
+
     /// Draws a box, starting at x/y with the extents width/height using CP437 line characters
     fn draw_box(&mut self, sx: i32, sy: i32, width: i32, height: i32, fg: RGBA, bg: RGBA) {
         crate::prelude::draw_box(self, sx, sy, width, height, fg, bg);
@@ -57,7 +57,17 @@ pub fn init_raw<S: ToString>(
         post_scanlines: false,
         post_screenburn: false,
         screen_burn_color: bracket_color::prelude::RGB::from_f32(0.0, 1.0, 1.0),
+        background_clear: bracket_color::prelude::RGBA::from_f32(0.0, 0.0, 0.0, 1.0),
         mouse_visible: true,
+        window_always_on_top: false,
+        window_decorations: true,
+        mouse_grabbed: false,
+        window_position: (0, 0),
+        fullscreen: false,
+        fullscreen_monitor: None,
+        run_time_seconds: 0.0,
+        tab_width: None,
+        text_outline: std::collections::HashMap::new(),
     };
     Ok(bterm)
 }
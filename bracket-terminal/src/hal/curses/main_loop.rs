@@ -37,6 +37,7 @@ pub fn main_loop<GS: GameState>(mut bterm: BTerm, mut gamestate: GS) -> BResult<
             bterm.frame_time_ms = (now_ms - prev_ms) as f32;
             prev_ms = now_ms;
         }
+        bterm.run_time_seconds = now_ms as f32 / 1000.0;
 
         // Input
         clear_input_state(&mut bterm);
@@ -13,17 +13,37 @@ pub struct Shader {
 /// a few more setters for uniforms)
 impl Shader {
     pub fn new(gl: &glow::Context, vertex_code: &str, fragment_code: &str) -> Shader {
+        match Shader::try_new(gl, vertex_code, fragment_code) {
+            Ok(shader) => shader,
+            Err(msg) => {
+                log(&msg);
+                panic!();
+            }
+        }
+    }
+
+    /// Compiles and links a shader program, returning the compiler/linker
+    /// info log on failure instead of panicking. Use this for user-supplied
+    /// source, where a typo shouldn't abort the program.
+    pub fn try_new(
+        gl: &glow::Context,
+        vertex_code: &str,
+        fragment_code: &str,
+    ) -> Result<Shader, String> {
         // 1. compile shaders from strings
-        let shader;
         unsafe {
             // vertex shader
             let vertex = gl.create_shader(glow::VERTEX_SHADER).unwrap();
             gl.shader_source(vertex, vertex_code);
             gl.compile_shader(vertex);
             if !gl.get_shader_compile_status(vertex) {
-                log(&vertex_code);
-                log(&gl.get_shader_info_log(vertex));
-                panic!();
+                let msg = format!(
+                    "Vertex shader failed to compile:\n{}\n{}",
+                    vertex_code,
+                    gl.get_shader_info_log(vertex)
+                );
+                gl.delete_shader(vertex);
+                return Err(msg);
             }
 
             // fragment Shader
@@ -31,9 +51,14 @@ impl Shader {
             gl.shader_source(fragment, fragment_code);
             gl.compile_shader(fragment);
             if !gl.get_shader_compile_status(fragment) {
-                log(&fragment_code);
-                log(&gl.get_shader_info_log(fragment));
-                panic!();
+                let msg = format!(
+                    "Fragment shader failed to compile:\n{}\n{}",
+                    fragment_code,
+                    gl.get_shader_info_log(fragment)
+                );
+                gl.delete_shader(vertex);
+                gl.delete_shader(fragment);
+                return Err(msg);
             }
 
             // shader Program
@@ -42,17 +67,19 @@ impl Shader {
             gl.attach_shader(id, fragment);
             gl.link_program(id);
             if !gl.get_program_link_status(id) {
-                log(&gl.get_program_info_log(id));
-                panic!();
+                let msg = format!(
+                    "Shader program failed to link:\n{}",
+                    gl.get_program_info_log(id)
+                );
+                gl.delete_shader(vertex);
+                gl.delete_shader(fragment);
+                gl.delete_program(id);
+                return Err(msg);
             }
 
             // delete the shaders as they're linked into our program now and no longer necessary
-            shader = Shader { ID: id }
+            Ok(Shader { ID: id })
         }
-
-        //log("Shaders Compiled");
-
-        shader
     }
 
     #[allow(non_snake_case)]
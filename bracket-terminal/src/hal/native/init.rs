@@ -15,7 +15,7 @@ use glutin::{
 use std::ffi::CString;
 use std::num::NonZeroU32;
 use winit::{
-    dpi::LogicalSize,
+    dpi::{LogicalSize, PhysicalPosition, PhysicalSize},
     event_loop::EventLoop,
     raw_window_handle::{HasRawDisplayHandle, HasRawWindowHandle},
     window::{Fullscreen, WindowAttributes},
@@ -28,12 +28,14 @@ pub fn init_raw<S: ToString>(
     platform_hints: InitHints,
 ) -> BResult<BTerm> {
     let mut scaler = ScreenScaler::new(platform_hints.desired_gutter, width_pixels, height_pixels);
+    scaler.integer_scaling = platform_hints.integer_scaling;
     let el = EventLoop::new()?;
     let window_size = scaler.new_window_size();
     let window_size = LogicalSize::new(window_size.width, window_size.height);
     let window_attributes = WindowAttributes::default()
         .with_title(window_title.to_string())
         .with_resizable(platform_hints.fitscreen)
+        .with_transparent(platform_hints.transparency)
         .with_min_inner_size(window_size)
         .with_inner_size(window_size);
     let window = el.create_window(window_attributes)?;
@@ -50,7 +52,7 @@ pub fn init_raw<S: ToString>(
 
     let mut template_builder = ConfigTemplateBuilder::new()
         .with_alpha_size(8)
-        .with_transparency(false)
+        .with_transparency(platform_hints.transparency)
         .with_surface_type(ConfigSurfaceTypes::WINDOW);
     if platform_hints.hardware_acceleration {
         template_builder = template_builder.prefer_hardware_accelerated(Some(true));
@@ -95,6 +97,7 @@ pub fn init_raw<S: ToString>(
         let _ = gl_surface.set_swap_interval(&gl_context, SwapInterval::DontWait);
     }
 
+    let mut start_fullscreen = platform_hints.fullscreen;
     if platform_hints.fullscreen {
         if let Some(mh) = window.available_monitors().next() {
             window.set_fullscreen(Some(Fullscreen::Borderless(Some(mh))));
@@ -103,6 +106,42 @@ pub fn init_raw<S: ToString>(
         }
     }
 
+    // Restore a previously saved window state, if one was provided. If the
+    // saved position is no longer on any monitor, fall back to centering on
+    // the current monitor.
+    if let Some(state) = platform_hints.window_state {
+        let _ = window.request_inner_size(PhysicalSize::new(state.width, state.height));
+        let on_screen = window.available_monitors().any(|m| {
+            let pos = m.position();
+            let size = m.size();
+            state.x >= pos.x
+                && state.y >= pos.y
+                && state.x < pos.x + size.width as i32
+                && state.y < pos.y + size.height as i32
+        });
+        if on_screen {
+            window.set_outer_position(PhysicalPosition::new(state.x, state.y));
+        } else if let Some(monitor) = window.current_monitor() {
+            let monitor_pos = monitor.position();
+            let monitor_size = monitor.size();
+            let window_size = window.outer_size();
+            window.set_outer_position(PhysicalPosition::new(
+                monitor_pos.x + (monitor_size.width.saturating_sub(window_size.width) / 2) as i32,
+                monitor_pos.y + (monitor_size.height.saturating_sub(window_size.height) / 2) as i32,
+            ));
+        }
+        if state.fullscreen && !start_fullscreen {
+            if let Some(mh) = window.available_monitors().next() {
+                window.set_fullscreen(Some(Fullscreen::Borderless(Some(mh))));
+                start_fullscreen = true;
+            }
+        }
+    }
+    let window_position = window
+        .outer_position()
+        .map(|p| (p.x, p.y))
+        .unwrap_or((0, 0));
+
     let gl = unsafe {
         glow::Context::from_loader_function(|ptr| {
             let symbol = CString::new(ptr).unwrap();
@@ -166,8 +205,22 @@ pub fn init_raw<S: ToString>(
     // Build a simple quad rendering VAO
     let quad_vao = setup_quad(&gl);
 
+    // Snapshot the attached monitors so BTerm::available_monitors() works
+    // before and during the main loop.
+    let monitors = window
+        .available_monitors()
+        .map(|m| crate::bterm::MonitorInfo {
+            name: m.name(),
+            width: m.size().width,
+            height: m.size().height,
+            refresh_rate_millihertz: m.refresh_rate_millihertz(),
+            scale_factor: m.scale_factor(),
+        })
+        .collect();
+
     let mut be = BACKEND.lock();
     be.gl = Some(gl);
+    be.monitors = monitors;
     be.quad_vao = Some(quad_vao);
     be.context_wrapper = Some(WrappedContext {
         el,
@@ -201,7 +254,22 @@ pub fn init_raw<S: ToString>(
         post_scanlines: false,
         post_screenburn: false,
         screen_burn_color: bracket_color::prelude::RGB::from_f32(0.0, 1.0, 1.0),
+        background_clear: if platform_hints.transparency {
+            // Zero-alpha clear so the desktop shows through unlit cells.
+            bracket_color::prelude::RGBA::from_f32(0.0, 0.0, 0.0, 0.0)
+        } else {
+            bracket_color::prelude::RGBA::from_f32(0.0, 0.0, 0.0, 1.0)
+        },
         mouse_visible: true,
+        window_always_on_top: false,
+        window_decorations: true,
+        mouse_grabbed: false,
+        window_position,
+        fullscreen: start_fullscreen,
+        fullscreen_monitor: None,
+        run_time_seconds: 0.0,
+        tab_width: None,
+        text_outline: std::collections::HashMap::new(),
     };
     Ok(bterm)
 }
@@ -11,7 +11,7 @@ use std::rc::Rc;
 use std::time::Instant;
 use winit::{
     dpi::PhysicalSize,
-    event::{ElementState, Event, MouseButton, WindowEvent},
+    event::{DeviceEvent, ElementState, Event, MouseButton, WindowEvent},
     event_loop::ControlFlow,
     keyboard::{KeyCode, PhysicalKey},
 };
@@ -154,13 +154,32 @@ pub fn main_loop<GS: GameState>(mut bterm: BTerm, mut gamestate: GS) -> BResult<
     )?; // Additional resize to handle some X11 cases
 
     let mut queued_resize_event: Option<ResizeEvent> = None;
+    let mut cursor_grabbed = false;
+    let mut current_fullscreen = bterm.fullscreen;
+    let mut current_fullscreen_monitor = bterm.fullscreen_monitor;
     #[cfg(feature = "low_cpu")]
     let spin_sleeper = spin_sleep::SpinSleeper::default();
     let my_window_id = window.id();
+    // Redraw immediately after startup, then (in redraw-on-input mode) only
+    // when something happens.
+    let mut redraw_pending = true;
 
     el.run(move |event, event_loop| {
-        event_loop.set_control_flow(TICK_TYPE);
-        let wait_time = BACKEND.lock().frame_sleep_time.unwrap_or(33); // Hoisted to reduce locks
+        let (wait_time, redraw_on_input, redraw_requested) = {
+            // Hoisted to reduce locks
+            let mut be = BACKEND.lock();
+            let requested = be.redraw_requested;
+            be.redraw_requested = false;
+            (be.frame_sleep_time.unwrap_or(33), be.redraw_on_input, requested)
+        };
+        if redraw_requested {
+            redraw_pending = true;
+        }
+        event_loop.set_control_flow(if redraw_on_input {
+            ControlFlow::Wait
+        } else {
+            TICK_TYPE
+        });
 
         if bterm.quitting {
             event_loop.exit();
@@ -168,14 +187,68 @@ pub fn main_loop<GS: GameState>(mut bterm: BTerm, mut gamestate: GS) -> BResult<
 
         match event {
             Event::AboutToWait => {
-                window.set_cursor_visible(bterm.mouse_visible);
-                window.request_redraw();
+                if bterm.mouse_grabbed != cursor_grabbed {
+                    use winit::window::CursorGrabMode;
+                    if bterm.mouse_grabbed {
+                        // Not every platform supports Locked; fall back to
+                        // confining the cursor to the window.
+                        if window.set_cursor_grab(CursorGrabMode::Locked).is_err() {
+                            let _ = window.set_cursor_grab(CursorGrabMode::Confined);
+                        }
+                    } else {
+                        let _ = window.set_cursor_grab(CursorGrabMode::None);
+                    }
+                    cursor_grabbed = bterm.mouse_grabbed;
+                }
+                window.set_cursor_visible(bterm.mouse_visible && !bterm.mouse_grabbed);
+                if let Some(ime_allowed) = BACKEND.lock().ime_request.take() {
+                    window.set_ime_allowed(ime_allowed);
+                }
+                if let Some((min, max)) = BACKEND.lock().size_constraint_request.take() {
+                    use winit::dpi::LogicalSize;
+                    window
+                        .set_min_inner_size(min.map(|(w, h)| LogicalSize::new(w as f64, h as f64)));
+                    window
+                        .set_max_inner_size(max.map(|(w, h)| LogicalSize::new(w as f64, h as f64)));
+                }
+                window.set_window_level(if bterm.window_always_on_top {
+                    winit::window::WindowLevel::AlwaysOnTop
+                } else {
+                    winit::window::WindowLevel::Normal
+                });
+                window.set_decorations(bterm.window_decorations);
+                if bterm.fullscreen != current_fullscreen
+                    || bterm.fullscreen_monitor != current_fullscreen_monitor
+                {
+                    if bterm.fullscreen {
+                        let monitor = bterm
+                            .fullscreen_monitor
+                            .and_then(|idx| window.available_monitors().nth(idx))
+                            .or_else(|| window.current_monitor());
+                        window
+                            .set_fullscreen(Some(winit::window::Fullscreen::Borderless(monitor)));
+                    } else {
+                        window.set_fullscreen(None);
+                    }
+                    current_fullscreen = bterm.fullscreen;
+                    current_fullscreen_monitor = bterm.fullscreen_monitor;
+                }
+                if !redraw_on_input || redraw_pending || queued_resize_event.is_some() {
+                    redraw_pending = false;
+                    window.request_redraw();
+                }
             }
             Event::WindowEvent { window_id, event } => {
                 if window_id != my_window_id {
                     return;
                 }
 
+                // In redraw-on-input mode, any window event (input, focus,
+                // moves, resizes) schedules a frame.
+                if !matches!(event, WindowEvent::RedrawRequested) {
+                    redraw_pending = true;
+                }
+
                 match event {
                     WindowEvent::RedrawRequested => {
                         let frame_timer = Instant::now();
@@ -184,7 +257,9 @@ pub fn main_loop<GS: GameState>(mut bterm: BTerm, mut gamestate: GS) -> BResult<
                         }
 
                         let execute_ms = now.elapsed().as_millis() as u64 - prev_ms as u64;
-                        if execute_ms >= wait_time {
+                        // Frames are rare in redraw-on-input mode; never skip
+                        // one to the FPS cap.
+                        if redraw_on_input || execute_ms >= wait_time {
                             if let Some(resize) = &queued_resize_event {
                                 resize_surface(&mut gl_surface, &gl_context, resize.physical_size);
                                 on_resize(
@@ -211,7 +286,7 @@ pub fn main_loop<GS: GameState>(mut bterm: BTerm, mut gamestate: GS) -> BResult<
                         }
 
                         let time_since_last_frame = frame_timer.elapsed().as_millis() as u64;
-                        if time_since_last_frame < wait_time {
+                        if !redraw_on_input && time_since_last_frame < wait_time {
                             let delay = u64::min(33, wait_time - time_since_last_frame);
                             #[cfg(not(feature = "low_cpu"))]
                             {
@@ -222,6 +297,7 @@ pub fn main_loop<GS: GameState>(mut bterm: BTerm, mut gamestate: GS) -> BResult<
                         }
                     }
                     WindowEvent::Moved(physical_position) => {
+                        bterm.window_position = (physical_position.x, physical_position.y);
                         bterm.on_event(BEvent::Moved {
                             new_position: Point::new(physical_position.x, physical_position.y),
                         });
@@ -252,6 +328,9 @@ pub fn main_loop<GS: GameState>(mut bterm: BTerm, mut gamestate: GS) -> BResult<
                         }
                     }
                     WindowEvent::Focused(focused) => {
+                        if !focused {
+                            INPUT.lock().clear_held_keys();
+                        }
                         bterm.on_event(BEvent::Focused { focused });
                     }
                     WindowEvent::CursorMoved { position: pos, .. } => {
@@ -286,7 +365,9 @@ pub fn main_loop<GS: GameState>(mut bterm: BTerm, mut gamestate: GS) -> BResult<
                     }
                     WindowEvent::KeyboardInput { event, .. } => {
                         if let Some(key) = physical_key_to_virtual_keycode(&event.physical_key) {
-                            bterm.on_key(key, 0, event.state == ElementState::Pressed);
+                            use winit::platform::scancode::PhysicalKeyExtScancode;
+                            let scan_code = event.physical_key.to_scancode().unwrap_or(0);
+                            bterm.on_key(key, scan_code, event.state == ElementState::Pressed);
                         }
                         if event.state == ElementState::Pressed {
                             if let Some(text) = event.text.as_ref() {
@@ -296,6 +377,15 @@ pub fn main_loop<GS: GameState>(mut bterm: BTerm, mut gamestate: GS) -> BResult<
                             }
                         }
                     }
+                    WindowEvent::Ime(ime) => match ime {
+                        winit::event::Ime::Preedit(text, cursor) => {
+                            bterm.on_event(BEvent::ImePreedit { text, cursor });
+                        }
+                        winit::event::Ime::Commit(text) => {
+                            bterm.on_event(BEvent::ImeCommit { text });
+                        }
+                        winit::event::Ime::Enabled | winit::event::Ime::Disabled => {}
+                    },
                     WindowEvent::ModifiersChanged(modifiers) => {
                         bterm.shift = modifiers.state().shift_key();
                         bterm.alt = modifiers.state().alt_key();
@@ -304,6 +394,17 @@ pub fn main_loop<GS: GameState>(mut bterm: BTerm, mut gamestate: GS) -> BResult<
                     _ => {}
                 }
             }
+            Event::DeviceEvent {
+                event: DeviceEvent::MouseMotion { delta },
+                ..
+            } => {
+                if bterm.mouse_grabbed {
+                    bterm.on_event(BEvent::MouseDelta {
+                        dx: delta.0 as f32,
+                        dy: delta.1 as f32,
+                    });
+                }
+            }
             _ => {}
         }
     })?;
@@ -458,6 +559,56 @@ fn keycode_to_virtual_keycode(code: &KeyCode) -> Option<VirtualKeyCode> {
     }
 }
 
+/// Loads (or unloads) any background images requested since the last frame.
+fn process_background_requests() {
+    let mut be = BACKEND.lock();
+    if be.background_requests.is_empty() {
+        return;
+    }
+    let requests: Vec<_> = be.background_requests.drain(..).collect();
+    for (console, path) in requests {
+        let old = match path {
+            Some(path) => {
+                // Reuse the font loader: it handles embedded resources and
+                // uploads the image as a GL texture.
+                let mut image = crate::prelude::Font::load(path, (1, 1), None);
+                let texture = image
+                    .setup_gl_texture(be.gl.as_ref().unwrap())
+                    .expect("Failed to load background image");
+                be.console_backgrounds.insert(console, texture)
+            }
+            None => be.console_backgrounds.remove(&console),
+        };
+        if let Some(old) = old {
+            unsafe {
+                be.gl.as_ref().unwrap().delete_texture(old);
+            }
+        }
+    }
+}
+
+/// Renders console background images into the backing buffer, before the
+/// glyphs. Consoles without a background render exactly as before.
+fn render_console_backgrounds() {
+    let be = BACKEND.lock();
+    if be.console_backgrounds.is_empty() {
+        return;
+    }
+    let gl = be.gl.as_ref().unwrap();
+    let bi = BACKEND_INTERNAL.lock();
+    unsafe {
+        bi.shaders[2].useProgram(gl);
+        gl.bind_vertex_array(Some(be.quad_vao.unwrap()));
+        // Draw in console order, so background stacking matches glyph layers.
+        let mut order: Vec<usize> = be.console_backgrounds.keys().copied().collect();
+        order.sort_unstable();
+        for console in order {
+            gl.bind_texture(glow::TEXTURE_2D, Some(be.console_backgrounds[&console]));
+            gl.draw_arrays(glow::TRIANGLES, 0, 6);
+        }
+    }
+}
+
 /// Internal handling of the main loop.
 fn tock<GS: GameState>(
     bterm: &mut BTerm,
@@ -485,6 +636,7 @@ fn tock<GS: GameState>(
         bterm.frame_time_ms = (now_ms - *prev_ms) as f32;
         *prev_ms = now_ms;
     }
+    bterm.run_time_seconds = now_ms as f32 / 1000.0;
 
     // Console structure - doesn't really have to be every frame...
     rebuild_consoles();
@@ -509,13 +661,18 @@ fn tock<GS: GameState>(
     // Clear the backing buffer
     unsafe {
         let be = BACKEND.lock();
-        be.gl.as_ref().unwrap().clear_color(0.0, 0.0, 0.0, 1.0);
+        let bg = bterm.background_clear;
+        be.gl.as_ref().unwrap().clear_color(bg.r, bg.g, bg.b, bg.a);
         be.gl.as_ref().unwrap().clear(glow::COLOR_BUFFER_BIT);
     }
 
     // Run the main loop
     gamestate.tick(bterm);
 
+    // Draw any console background images beneath the glyphs
+    process_background_requests();
+    render_console_backgrounds();
+
     // Tell each console to draw itself
     render_consoles().unwrap();
 
@@ -543,11 +700,21 @@ fn tock<GS: GameState>(
                 be.screen_scaler.physical_size.0 as i32,
                 be.screen_scaler.physical_size.1 as i32,
             );
-            be.gl.as_ref().unwrap().clear_color(0.0, 0.0, 0.0, 1.0);
+            let bg = bterm.background_clear;
+            be.gl.as_ref().unwrap().clear_color(bg.r, bg.g, bg.b, bg.a);
             be.gl.as_ref().unwrap().clear(glow::COLOR_BUFFER_BIT);
 
             let bi = BACKEND_INTERNAL.lock();
-            if bterm.post_scanlines {
+            if let Some(custom) = be.custom_post_shader {
+                bi.shaders[custom].useProgram(be.gl.as_ref().unwrap());
+                bi.shaders[custom].setVec3(
+                    be.gl.as_ref().unwrap(),
+                    "screenSize",
+                    scale_factor * bterm.width_pixels as f32,
+                    scale_factor * bterm.height_pixels as f32,
+                    0.0,
+                );
+            } else if bterm.post_scanlines {
                 bi.shaders[3].useProgram(be.gl.as_ref().unwrap());
                 bi.shaders[3].setVec3(
                     be.gl.as_ref().unwrap(),
@@ -11,6 +11,7 @@ pub use keycodes::VirtualKeyCode;
 pub use mainloop::*;
 use parking_lot::Mutex;
 use std::any::Any;
+use std::collections::HashMap;
 
 pub type GlCallback = fn(&mut dyn Any, &glow::Context);
 
@@ -26,6 +27,14 @@ lazy_static! {
         resize_request: None,
         request_screenshot: None,
         screen_scaler: ScreenScaler::default(),
+        monitors: Vec::new(),
+        background_requests: Vec::new(),
+        console_backgrounds: HashMap::new(),
+        redraw_on_input: false,
+        redraw_requested: false,
+        ime_request: None,
+        size_constraint_request: None,
+        custom_post_shader: None,
     });
 }
 
@@ -44,6 +53,25 @@ pub struct PlatformGL {
     pub resize_request: Option<(u32, u32)>,
     pub request_screenshot: Option<String>,
     pub screen_scaler: ScreenScaler,
+    pub monitors: Vec<crate::bterm::MonitorInfo>,
+    /// Pending background image changes: `(console, Some(path))` to set,
+    /// `(console, None)` to clear. Processed by the main loop.
+    pub(crate) background_requests: Vec<(usize, Option<String>)>,
+    /// Loaded background textures, keyed by console index.
+    pub(crate) console_backgrounds: HashMap<usize, super::TextureId>,
+    /// When set, the main loop waits for events instead of polling, and only
+    /// redraws on input, resize, or an explicit redraw request.
+    pub(crate) redraw_on_input: bool,
+    /// One-shot redraw request, consumed by the main loop.
+    pub(crate) redraw_requested: bool,
+    /// Pending IME enable/disable request, consumed by the main loop.
+    pub(crate) ime_request: Option<bool>,
+    /// Pending window size constraints as `(min, max)` in logical pixels,
+    /// `None` inside the tuple clearing that bound. Consumed by the main loop.
+    pub(crate) size_constraint_request: Option<(Option<(u32, u32)>, Option<(u32, u32)>)>,
+    /// Index into the shader list of a user-supplied shader that replaces the
+    /// built-in final composite pass. See `BTerm::set_post_shader`.
+    pub(crate) custom_post_shader: Option<usize>,
 }
 
 unsafe impl Send for PlatformGL {}
@@ -67,6 +95,9 @@ pub struct InitHints {
     pub resize_scaling: bool,
     pub desired_gutter: u32,
     pub fitscreen: bool,
+    pub integer_scaling: bool,
+    pub window_state: Option<crate::bterm::WindowState>,
+    pub transparency: bool,
 }
 
 impl InitHints {
@@ -85,8 +116,30 @@ impl InitHints {
             resize_scaling: false,
             desired_gutter: default_gutter_size(),
             fitscreen: false,
+            integer_scaling: false,
+            window_state: None,
+            transparency: false,
         }
     }
+
+    /// Restores a previously captured `BTerm::window_state()` snapshot at
+    /// initialization time. If the saved position is off all current
+    /// monitors, the window falls back to centered.
+    pub fn with_window_state(mut self, state: crate::bterm::WindowState) -> Self {
+        self.window_state = Some(state);
+        self
+    }
+
+    /// Requests a transparent window: the GL config is created with an alpha
+    /// channel and the final clear each frame uses a zero-alpha color, so
+    /// whatever is behind the window shows through unlit cells. Requires a
+    /// compositing window manager (standard on Windows/macOS; on Linux this
+    /// needs a compositor running), and is ignored by drivers that don't
+    /// offer a transparent config.
+    pub fn with_transparency(mut self, transparency: bool) -> Self {
+        self.transparency = transparency;
+        self
+    }
 }
 
 impl Default for InitHints {
@@ -105,6 +158,9 @@ impl Default for InitHints {
             resize_scaling: false,
             desired_gutter: default_gutter_size(),
             fitscreen: false,
+            integer_scaling: false,
+            window_state: None,
+            transparency: false,
         }
     }
 }
@@ -187,6 +187,7 @@ void main()
     vec2 center_pos = aRotate.yz;
     vec2 base_pos = aPos.xy - center_pos;
     base_pos *= r2d(rot);
+    // Signed scale: negative components mirror the glyph around its center.
     base_pos *= aScale;
     base_pos += center_pos;
 
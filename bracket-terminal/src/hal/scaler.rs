@@ -74,6 +74,7 @@ pub struct ScreenScaler {
     pub gutter_bottom: u32,
     pub available_width: u32,
     pub available_height: u32,
+    pub integer_scaling: bool,
     aspect_ratio: f32,
     resized: bool,
 }
@@ -93,6 +94,7 @@ impl ScreenScaler {
             gutter_bottom: 0,
             available_width: 0,
             available_height: 0,
+            integer_scaling: false,
             aspect_ratio: 1.0,
             resized: true,
         }
@@ -112,6 +114,7 @@ impl ScreenScaler {
             gutter_bottom: 0,
             available_width: 0,
             available_height: 0,
+            integer_scaling: false,
             aspect_ratio: desired_height as f32 / desired_width as f32,
             resized: true,
         };
@@ -154,6 +157,23 @@ impl ScreenScaler {
         self.physical_size.0 = width;
         self.physical_size.1 = height;
 
+        if self.integer_scaling && self.logical_size.0 > 0 && self.logical_size.1 > 0 {
+            // Pixel-perfect mode: snap the output to the largest integer
+            // multiple of the logical size that fits, and push the fractional
+            // leftover into the gutters instead of stretching the image.
+            let multiple = u32::max(
+                1,
+                u32::min(
+                    width / self.logical_size.0,
+                    height / self.logical_size.1,
+                ),
+            );
+            self.smooth_gutter_x = width.saturating_sub(self.logical_size.0 * multiple);
+            self.smooth_gutter_y = height.saturating_sub(self.logical_size.1 * multiple);
+            self.recalculate_coordinates();
+            return;
+        }
+
         let mut desired_y = (width as f32 * self.aspect_ratio) as u32;
         desired_y -= desired_y % max_font.1;
 
@@ -224,6 +244,12 @@ impl ScreenScaler {
         )
     }
 
+    /// Returns the DPI-adjusted scale factor currently applied when mapping
+    /// logical coordinates to the physical backing buffer.
+    pub fn scale_factor(&self) -> f32 {
+        self.scale_factor
+    }
+
     pub fn get_resized_and_reset(&mut self) -> bool {
         let result = self.resized;
         self.resized = false;
@@ -105,6 +105,7 @@ fn tock<GS: GameState>(
         bterm.frame_time_ms = (now_ms - *prev_ms) as f32;
         *prev_ms = now_ms;
     }
+    bterm.run_time_seconds = now_ms as f32 / 1000.0;
 
     gamestate.tick(bterm);
 
@@ -123,7 +124,8 @@ fn tock<GS: GameState>(
     // Clear the screen
     unsafe {
         let be = BACKEND.lock();
-        be.gl.as_ref().unwrap().clear_color(0.0, 0.0, 0.0, 1.0);
+        let bg = bterm.background_clear;
+        be.gl.as_ref().unwrap().clear_color(bg.r, bg.g, bg.b, bg.a);
         be.gl.as_ref().unwrap().clear(glow::COLOR_BUFFER_BIT);
     }
 
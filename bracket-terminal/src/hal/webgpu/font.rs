@@ -49,7 +49,7 @@ impl Font {
         }
     }
 
-    fn load_image(filename: &str) -> image::DynamicImage {
+    pub(crate) fn load_image(filename: &str) -> image::DynamicImage {
         let resource = EMBED.lock().get_resource(filename.to_string());
         match resource {
             None => image::open(std::path::Path::new(&filename.to_string()))
@@ -19,6 +19,7 @@ pub fn init_raw<S: ToString>(
     platform_hints: InitHints,
 ) -> BResult<BTerm> {
     let mut scaler = ScreenScaler::new(platform_hints.desired_gutter, width_pixels, height_pixels);
+    scaler.integer_scaling = platform_hints.integer_scaling;
     let el = EventLoop::new();
     let wb = WindowBuilder::new()
         .with_title(window_title.to_string())
@@ -102,7 +103,17 @@ pub fn init_raw<S: ToString>(
         post_scanlines: false,
         post_screenburn: false,
         screen_burn_color: bracket_color::prelude::RGB::from_f32(0.0, 1.0, 1.0),
+        background_clear: bracket_color::prelude::RGBA::from_f32(0.0, 0.0, 0.0, 1.0),
         mouse_visible: true,
+        window_always_on_top: false,
+        window_decorations: true,
+        mouse_grabbed: false,
+        window_position: (0, 0),
+        fullscreen: false,
+        fullscreen_monitor: None,
+        run_time_seconds: 0.0,
+        tab_width: None,
+        text_outline: std::collections::HashMap::new(),
     };
     Ok(bterm)
 }
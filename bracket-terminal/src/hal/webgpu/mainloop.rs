@@ -171,6 +171,9 @@ pub fn main_loop<GS: GameState>(mut bterm: BTerm, mut gamestate: GS) -> BResult<
                         bterm.on_event(BEvent::Character { c: *char });
                     }
                     WindowEvent::Focused(focused) => {
+                        if !focused {
+                            INPUT.lock().clear_held_keys();
+                        }
                         bterm.on_event(BEvent::Focused { focused: *focused });
                     }
                     WindowEvent::CursorMoved { position: pos, .. } => {
@@ -343,6 +346,7 @@ fn tock<GS: GameState>(
         bterm.frame_time_ms = (now_ms - *prev_ms) as f32;
         *prev_ms = now_ms;
     }
+    bterm.run_time_seconds = now_ms as f32 / 1000.0;
 
     // Console structure - doesn't really have to be every frame...
     rebuild_consoles();
@@ -49,6 +49,7 @@ pub struct InitHints {
     pub resize_scaling: bool,
     pub desired_gutter: u32,
     pub fitscreen: bool,
+    pub integer_scaling: bool,
 }
 
 impl InitHints {
@@ -60,6 +61,7 @@ impl InitHints {
             resize_scaling: false,
             desired_gutter: default_gutter_size(),
             fitscreen: false,
+            integer_scaling: false,
         }
     }
 }
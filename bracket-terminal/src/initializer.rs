@@ -484,6 +484,19 @@ impl BTermBuilder {
         self
     }
 
+    /// Request pixel-perfect scaling: the rendered output snaps to an
+    /// integer multiple of the logical size, with leftover pixels going
+    /// into the gutter instead of stretching the image. Native OpenGL and
+    /// WebGPU only.
+    #[cfg(all(
+        any(feature = "opengl", feature = "webgpu"),
+        not(target_arch = "wasm32")
+    ))]
+    pub fn with_integer_scaling(mut self, integer_scaling: bool) -> Self {
+        self.platform_hints.integer_scaling = integer_scaling;
+        self
+    }
+
     /// Register a sprite sheet
     #[cfg(any(feature = "opengl", feature = "webgpu"))]
     pub fn with_sprite_sheet(mut self, ss: SpriteSheet) -> Self {
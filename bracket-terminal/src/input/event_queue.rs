@@ -31,6 +31,10 @@ pub enum BEvent {
     /// The mouse cursor moved
     CursorMoved { position: Point },
 
+    /// Relative mouse motion from the raw device, in unscaled device units.
+    /// Only emitted while the cursor is grabbed via `BTerm::set_cursor_grab`.
+    MouseDelta { dx: f32, dy: f32 },
+
     /// A mouse button was pressed or released
     MouseClick { button: usize, pressed: bool },
 
@@ -40,13 +44,35 @@ pub enum BEvent {
     /// Mouse button is up
     MouseButtonUp { button: usize },
 
-    /// A key on the keyboard was pressed or released.
+    /// A key on the keyboard was pressed or released. The modifier states are
+    /// sampled at the time of the event, so they can't race the separate
+    /// `shift`/`control`/`alt` booleans on `BTerm`.
     KeyboardInput {
         key: VirtualKeyCode,
         scan_code: u32,
         pressed: bool,
+        shift: bool,
+        control: bool,
+        alt: bool,
     },
 
+    /// A console layer's character grid was resized at runtime, via
+    /// `BTerm::set_console_char_size`.
+    ConsoleResized { console: usize, new_size: Point },
+
+    /// An IME composition is in progress. `text` is the uncommitted preedit
+    /// string, and `cursor` the byte range of the composition cursor within
+    /// it (`None` when the composition is hidden). Only sent after
+    /// `BTerm::set_ime_enabled(true)`.
+    ImePreedit {
+        text: String,
+        cursor: Option<(usize, usize)>,
+    },
+
+    /// An IME composition was committed; `text` is the finished string and
+    /// should be inserted into the focused text field.
+    ImeCommit { text: String },
+
     /// The window's scale factor was changed. You generally don't need to do anything for this, unless you are working with
     /// pixel coordinates.
     ScaleFactorChanged {
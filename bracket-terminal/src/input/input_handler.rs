@@ -144,6 +144,13 @@ impl Input {
         self.scancodes.remove(&scan_code);
     }
 
+    /// Internal - clears all held keys. Called on focus loss, so releases
+    /// that happened while the window was unfocused don't leave keys stuck.
+    pub(crate) fn clear_held_keys(&mut self) {
+        self.keys_down.clear();
+        self.scancodes.clear();
+    }
+
     /// Internal - do not use
     pub(crate) fn on_mouse_button_down(&mut self, button_num: usize) {
         self.mouse_buttons.insert(button_num);